@@ -0,0 +1,52 @@
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use serde_tuplex::{LenientValue, TupleLenient};
+
+#[derive(TupleLenient)]
+#[allow(dead_code)]
+struct Trade {
+    timestamp: u64,
+    price: f64,
+    quantity: u32,
+    side: i8,
+}
+
+fn bench_lenient_value(c: &mut Criterion) {
+    let integer: LenientValue = serde_json::from_str("1609459200").unwrap();
+    let signed: LenientValue = serde_json::from_str("-42").unwrap();
+    let float: LenientValue = serde_json::from_str("1234.5678").unwrap();
+    let string: LenientValue = serde_json::from_str(r#""1609459200""#).unwrap();
+
+    c.bench_function("parse u64 from u64", |b| {
+        b.iter(|| black_box(&integer).parse::<u64>().unwrap())
+    });
+    c.bench_function("parse i32 from i64", |b| {
+        b.iter(|| black_box(&signed).parse::<i32>().unwrap())
+    });
+    c.bench_function("parse f64 from f64", |b| {
+        b.iter(|| black_box(&float).parse::<f64>().unwrap())
+    });
+    c.bench_function("parse u64 from string", |b| {
+        b.iter(|| black_box(&string).parse::<u64>().unwrap())
+    });
+}
+
+fn bench_tuple_lenient(c: &mut Criterion) {
+    let numbers: String = format!(
+        "[{}]",
+        vec!["[1609459200,1234.5678,250,-1]"; 1000].join(",")
+    );
+    let strings: String = format!(
+        "[{}]",
+        vec![r#"["1609459200","1234.5678","250","-1"]"#; 1000].join(",")
+    );
+
+    c.bench_function("1000 TupleLenient rows (numbers)", |b| {
+        b.iter(|| serde_json::from_str::<Vec<Trade>>(black_box(&numbers)).unwrap())
+    });
+    c.bench_function("1000 TupleLenient rows (strings)", |b| {
+        b.iter(|| serde_json::from_str::<Vec<Trade>>(black_box(&strings)).unwrap())
+    });
+}
+
+criterion_group!(benches, bench_lenient_value, bench_tuple_lenient);
+criterion_main!(benches);
@@ -1,6 +1,11 @@
 //! Type analysis for determining lenient parsing behavior.
 
-use syn::{Field, Type};
+use syn::spanned::Spanned;
+use syn::{Expr, Field, LitStr, RangeLimits, Type};
+
+/// The `bool` vocabulary of `serde_tuplex`, used when `true_values`/`false_values` is left out.
+const DEFAULT_TRUE_VALUES: &[&str] = &["true", "t", "yes", "y", "on", "1"];
+const DEFAULT_FALSE_VALUES: &[&str] = &["false", "f", "no", "n", "off", "0"];
 
 /// Check if a type is a primitive numeric type.
 pub fn is_base_numeric(ty: &Type) -> bool {
@@ -27,22 +32,181 @@ pub fn is_base_numeric(ty: &Type) -> bool {
     false
 }
 
-/// Check if type is numeric or `Option<numeric>`.
+/// Check if a type is `f32` or `f64`.
+pub fn is_base_float(ty: &Type) -> bool {
+    if let Type::Path(type_path) = ty
+        && let Some(ident) = type_path.path.get_ident()
+    {
+        return ident == "f32" || ident == "f64";
+    }
+    false
+}
+
+/// Check if a type is a primitive integer.
+pub fn is_base_integer(ty: &Type) -> bool {
+    is_base_numeric(ty) && !is_base_float(ty)
+}
+
+/// Check if a path type names one of `names` from one of `crates`, e.g. `num_bigint::BigInt`.
+/// A bare `BigInt` only counts when `bare` is set, since any crate may define that name.
+fn is_crate_type(ty: &Type, crates: &[&str], names: &[&str], bare: bool) -> bool {
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+    let segments = &type_path.path.segments;
+    let (Some(first), Some(last)) = (segments.first(), segments.last()) else {
+        return false;
+    };
+    if !names.iter().any(|name| last.ident == name) {
+        return false;
+    }
+    if segments.len() == 1 {
+        return bare && type_path.path.leading_colon.is_none();
+    }
+    crates.iter().any(|krate| first.ident == krate)
+}
+
+/// Check if a type is a decimal whose crate feature is on: `rust_decimal::Decimal` or
+/// `bigdecimal::BigDecimal`, or the bare name when `bare` is set.
+pub fn is_decimal_type(ty: &Type, bare: bool) -> bool {
+    (cfg!(feature = "rust_decimal") && is_crate_type(ty, &["rust_decimal"], &["Decimal"], bare))
+        || (cfg!(feature = "bigdecimal")
+            && is_crate_type(ty, &["bigdecimal"], &["BigDecimal"], bare))
+}
+
+/// Check if a type is a big integer whose crate feature is on: `num_bigint::BigInt`/`BigUint`
+/// (also via `num::`) or `primitive_types::U256`, or the bare name when `bare` is set.
+pub fn is_big_integer_type(ty: &Type, bare: bool) -> bool {
+    (cfg!(feature = "num-bigint")
+        && is_crate_type(ty, &["num_bigint", "num"], &["BigInt", "BigUint"], bare))
+        || (cfg!(feature = "primitive-types")
+            && is_crate_type(ty, &["primitive_types"], &["U256"], bare))
+}
+
+/// Check if a type is a `std::num` wrapper around a primitive integer, by any path:
+/// `NonZeroU32`, `NonZero<u32>`, `Wrapping<u64>` or `Saturating<i32>`.
+pub fn is_integer_wrapper_type(ty: &Type) -> bool {
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+    let Some(segment) = type_path.path.segments.last() else {
+        return false;
+    };
+    let name = segment.ident.to_string();
+    if let Some(inner) = name.strip_prefix("NonZero")
+        && !inner.is_empty()
+    {
+        return matches!(
+            inner,
+            "U8" | "U16"
+                | "U32"
+                | "U64"
+                | "U128"
+                | "Usize"
+                | "I8"
+                | "I16"
+                | "I32"
+                | "I64"
+                | "I128"
+                | "Isize"
+        );
+    }
+    if matches!(name.as_str(), "NonZero" | "Wrapping" | "Saturating")
+        && let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+        && let Some(syn::GenericArgument::Type(inner)) = args.args.first()
+    {
+        return is_base_integer(inner);
+    }
+    false
+}
+
+/// Check if type is numeric or `Option<numeric>`, including the `std::num` integer wrappers;
+/// decimals and big integers count by their crate path when their feature is on.
 pub fn is_numeric_type(ty: &Type) -> bool {
-    if is_base_numeric(ty) {
+    let ty = value_type(ty);
+    is_base_numeric(ty)
+        || is_integer_wrapper_type(ty)
+        || is_decimal_type(ty, false)
+        || is_big_integer_type(ty, false)
+}
+
+/// Check if type is `bool` or `Option<bool>`.
+pub fn is_bool_type(ty: &Type) -> bool {
+    let is_bool = |ty: &Type| matches!(ty, Type::Path(p) if p.path.is_ident("bool"));
+    is_bool(value_type(ty))
+}
+
+/// Check if type is `String`, `Box<str>` or an `Option` of either.
+pub fn is_string_type(ty: &Type) -> bool {
+    let Type::Path(type_path) = value_type(ty) else {
+        return false;
+    };
+    if type_path.path.is_ident("String") {
         return true;
     }
-
-    if let Type::Path(type_path) = ty
-        && type_path.path.segments.len() == 1
+    if type_path.path.segments.len() == 1
+        && let segment = &type_path.path.segments[0]
+        && segment.ident == "Box"
+        && let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+        && let Some(syn::GenericArgument::Type(Type::Path(inner))) = args.args.first()
     {
-        let segment = &type_path.path.segments[0];
-        if segment.ident == "Option"
-            && let syn::PathArguments::AngleBracketed(args) = &segment.arguments
-            && let Some(syn::GenericArgument::Type(inner_ty)) = args.args.first()
-        {
-            return is_base_numeric(inner_ty);
+        return inner.path.is_ident("str");
+    }
+    false
+}
+
+/// Check if type is `std::time::Duration` (also as `core::time::Duration` or a bare
+/// `Duration`) or an `Option` of it.
+pub fn is_duration_type(ty: &Type) -> bool {
+    let Type::Path(type_path) = value_type(ty) else {
+        return false;
+    };
+    let path = &type_path.path;
+    let idents: Vec<_> = path.segments.iter().map(|s| s.ident.to_string()).collect();
+    match idents.as_slice() {
+        [name] => name == "Duration" && path.leading_colon.is_none(),
+        [krate, module, name] => {
+            (krate == "std" || krate == "core") && module == "time" && name == "Duration"
         }
+        _ => false,
+    }
+}
+
+/// Error for a `Duration` from another crate, such as `chrono::Duration`, where a
+/// `std::time::Duration` is required.
+fn other_duration_error(ty: &Type) -> Option<syn::Error> {
+    let inner = value_type(ty);
+    let Type::Path(type_path) = inner else {
+        return None;
+    };
+    if is_duration_type(ty) || type_path.path.segments.last()?.ident != "Duration" {
+        return None;
+    }
+    let name = quote::quote!(#inner).to_string().replace(' ', "");
+    Some(syn::Error::new_spanned(
+        inner,
+        format!("`{}` is not supported; use `std::time::Duration`", name),
+    ))
+}
+
+/// Check if type is a timestamp whose crate feature is on: `DateTime<Utc>` with `chrono`,
+/// `OffsetDateTime` with `time`, or an `Option` of either.
+pub fn is_timestamp_type(ty: &Type) -> bool {
+    let Type::Path(type_path) = value_type(ty) else {
+        return false;
+    };
+    let Some(segment) = type_path.path.segments.last() else {
+        return false;
+    };
+    if cfg!(feature = "time") && segment.ident == "OffsetDateTime" {
+        return true;
+    }
+    if cfg!(feature = "chrono")
+        && segment.ident == "DateTime"
+        && let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+        && let Some(syn::GenericArgument::Type(Type::Path(tz))) = args.args.first()
+    {
+        return tz.path.segments.last().is_some_and(|s| s.ident == "Utc");
     }
     false
 }
@@ -58,37 +222,957 @@ pub fn is_option_type(ty: &Type) -> bool {
     false
 }
 
-/// Extract `#[serde_tuplex(skip)]` attribute.
-///
-/// Returns `Some(false)` if skip is present, `Some(true)` if attribute exists without skip, `None` otherwise.
-pub fn get_serde_tuplex_attr(field: &Field) -> Option<bool> {
-    for attr in &field.attrs {
-        if attr
-            .path()
-            .get_ident()
-            .map(|i| i == "serde_tuplex")
-            .unwrap_or(false)
+/// Extract `T` from `Option<T>`.
+pub fn option_inner_type(ty: &Type) -> Option<&Type> {
+    if let Type::Path(type_path) = ty
+        && type_path.path.segments.len() == 1
+    {
+        let segment = &type_path.path.segments[0];
+        if segment.ident == "Option"
+            && let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+            && let Some(syn::GenericArgument::Type(inner_ty)) = args.args.first()
+        {
+            return Some(inner_ty);
+        }
+    }
+    None
+}
+
+/// Type with any `Option<...>` wrapper removed.
+pub fn value_type(ty: &Type) -> &Type {
+    option_inner_type(ty).unwrap_or(ty)
+}
+
+/// Out-of-range handling from `#[serde_tuplex(overflow = "...")]`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Overflow {
+    Error,
+    Saturate,
+    Wrap,
+}
+
+/// NaN/infinity handling from `#[serde_tuplex(non_finite = "...")]`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum NonFinite {
+    Allow,
+    Reject,
+    Null,
+}
+
+impl NonFinite {
+    fn parse(meta: &syn::meta::ParseNestedMeta) -> syn::Result<Self> {
+        let lit: LitStr = meta.value()?.parse()?;
+        Ok(match lit.value().as_str() {
+            "allow" => NonFinite::Allow,
+            "reject" => NonFinite::Reject,
+            "null" => NonFinite::Null,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    "expected \"reject\", \"allow\" or \"null\"",
+                ));
+            }
+        })
+    }
+}
+
+/// Serialized form from `#[serde_tuplex(serialize = "...")]`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum FieldRepr {
+    Hex,
+    NonFiniteString,
+    Human,
+    Iso8601,
+    Si,
+    Percent,
+    Epoch,
+    String,
+    Number,
+}
+
+/// Number format from `number_format = "de"` or
+/// `number_format(grouping = ".", decimal = ",")`; `grouping` defaults to none and `decimal`
+/// to `.`.
+#[derive(Clone)]
+pub enum NumberFormatAttr {
+    Profile(syn::Ident),
+    Custom { grouping: Vec<char>, decimal: char },
+}
+
+impl NumberFormatAttr {
+    const PROFILES: &[&str] = &["en", "de", "fr", "ch"];
+
+    fn parse(meta: &syn::meta::ParseNestedMeta) -> syn::Result<Self> {
+        if meta.input.peek(syn::Token![=]) {
+            let lit: LitStr = meta.value()?.parse()?;
+            let name = lit.value();
+            if !Self::PROFILES.contains(&name.as_str()) {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    "expected \"en\", \"de\", \"fr\" or \"ch\"",
+                ));
+            }
+            return Ok(NumberFormatAttr::Profile(syn::Ident::new(
+                &name.to_uppercase(),
+                lit.span(),
+            )));
+        }
+
+        let mut grouping = None;
+        let mut decimal = None;
+        meta.parse_nested_meta(|inner| {
+            let lit: LitStr = inner.value()?.parse()?;
+            let chars: Vec<char> = lit.value().chars().collect();
+            if chars
+                .iter()
+                .any(|c| c.is_ascii_digit() || matches!(c, '+' | '-' | 'e' | 'E'))
+            {
+                return Err(syn::Error::new_spanned(
+                    &lit,
+                    "separators cannot be digits, signs or exponent markers",
+                ));
+            }
+            if inner.path.is_ident("grouping") {
+                if chars.is_empty() {
+                    return Err(syn::Error::new_spanned(
+                        &lit,
+                        "expected at least one character",
+                    ));
+                }
+                grouping = Some(chars);
+            } else if inner.path.is_ident("decimal") {
+                let [c] = chars[..] else {
+                    return Err(syn::Error::new_spanned(&lit, "expected a single character"));
+                };
+                decimal = Some(c);
+            } else {
+                return Err(inner.error("expected `grouping` or `decimal`"));
+            }
+            Ok(())
+        })?;
+
+        if grouping.is_none() && decimal.is_none() {
+            return Err(meta.error("`number_format(...)` requires `grouping` or `decimal`"));
+        }
+        let grouping = grouping.unwrap_or_default();
+        let decimal = decimal.unwrap_or('.');
+        if grouping.contains(&decimal) {
+            return Err(meta.error("the decimal mark cannot also be a grouping separator"));
+        }
+        Ok(NumberFormatAttr::Custom { grouping, decimal })
+    }
+
+    /// Expression building the runtime `NumberFormat`.
+    pub fn to_tokens(&self) -> proc_macro2::TokenStream {
+        match self {
+            NumberFormatAttr::Profile(name) => {
+                quote::quote! { ::serde_tuplex::NumberFormat::#name }
+            }
+            NumberFormatAttr::Custom { grouping, decimal } => quote::quote! {
+                ::serde_tuplex::NumberFormat::new(&[#(#grouping),*], #decimal)
+            },
+        }
+    }
+}
+
+/// String clean-up from `normalize = "none" | "default"` or
+/// `normalize(trim, plus_sign, unicode_minus, full_width, underscores)`.
+#[derive(Clone)]
+pub struct NormalizeAttr(Vec<syn::Ident>);
+
+impl NormalizeAttr {
+    const STEPS: &[&str] = &[
+        "trim",
+        "plus_sign",
+        "unicode_minus",
+        "full_width",
+        "underscores",
+    ];
+
+    fn parse(meta: &syn::meta::ParseNestedMeta) -> syn::Result<Self> {
+        if meta.input.peek(syn::Token![=]) {
+            let lit: LitStr = meta.value()?.parse()?;
+            let set = match lit.value().as_str() {
+                "none" => "NONE",
+                "default" => "DEFAULT",
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        lit,
+                        "expected \"none\" or \"default\"",
+                    ));
+                }
+            };
+            return Ok(NormalizeAttr(vec![syn::Ident::new(set, lit.span())]));
+        }
+
+        let mut steps = vec![syn::Ident::new("NONE", meta.path.span())];
+        meta.parse_nested_meta(|inner| {
+            let Some(ident) = inner.path.get_ident() else {
+                return Err(inner.error("expected a normalization step"));
+            };
+            if !Self::STEPS.contains(&ident.to_string().as_str()) {
+                return Err(inner.error(
+                    "expected `trim`, `plus_sign`, `unicode_minus`, `full_width` or `underscores`",
+                ));
+            }
+            steps.push(syn::Ident::new(
+                &ident.to_string().to_uppercase(),
+                ident.span(),
+            ));
+            Ok(())
+        })?;
+        Ok(NormalizeAttr(steps))
+    }
+
+    /// Expression building the runtime `Normalize` set.
+    pub fn to_tokens(&self) -> proc_macro2::TokenStream {
+        let steps = &self.0;
+        quote::quote! { #(::serde_tuplex::Normalize::#steps)|* }
+    }
+}
+
+/// Allowed range from `range = a..=b`, `min = a` and `max = b`.
+#[derive(Default)]
+pub struct Bounds {
+    pub start: Option<Expr>,
+    pub end: Option<Expr>,
+    /// Whether `end` is inclusive (`..=` or `max`).
+    pub inclusive: bool,
+}
+
+impl Bounds {
+    pub fn is_empty(&self) -> bool {
+        self.start.is_none() && self.end.is_none()
+    }
+
+    /// Human-readable form for error messages, e.g. `1..=65535`.
+    pub fn describe(&self) -> String {
+        let bound = |expr: &Option<Expr>| {
+            expr.as_ref()
+                .map(|e| quote::quote!(#e).to_string().replace(' ', ""))
+                .unwrap_or_default()
+        };
+        let limits = if self.inclusive && self.end.is_some() {
+            "..="
+        } else {
+            ".."
+        };
+        format!("{}{}{}", bound(&self.start), limits, bound(&self.end))
+    }
+}
+
+/// Field-level `#[serde_tuplex(...)]` attributes.
+#[derive(Default)]
+pub struct FieldAttrs {
+    /// `skip`: keep strict serde parsing.
+    pub skip: bool,
+    /// `lenient`: force lenient parsing for non-numeric types.
+    pub lenient: bool,
+    /// `stringify`: accept numbers and booleans on a string field.
+    pub stringify: bool,
+    /// `overflow = "saturate" | "wrap" | "error"`.
+    pub overflow: Option<Overflow>,
+    /// `range = a..=b`, `min = a`, `max = b`.
+    pub bounds: Bounds,
+    /// `parse_with = "path"`: custom parser called instead of `LenientValue::parse`.
+    pub parse_with: Option<syn::ExprPath>,
+    /// `true_values = ["..."]`: strings read as `true` on a `bool` field.
+    pub true_values: Option<Vec<LitStr>>,
+    /// `false_values = ["..."]`: strings read as `false` on a `bool` field.
+    pub false_values: Option<Vec<LitStr>>,
+    /// `radix_prefix`: accept `0x`/`0o`/`0b` prefixed integer strings.
+    pub radix_prefix: bool,
+    /// `serialize = "hex"`: output form for `Tuple`/`TupleLenient` serialization.
+    pub serialize: Option<FieldRepr>,
+    /// `number_format = ...`: locale of number strings.
+    pub number_format: Option<NumberFormatAttr>,
+    /// `normalize = ...`: string clean-up before parsing.
+    pub normalize: Option<NormalizeAttr>,
+    /// `non_finite = "reject" | "allow" | "null"`: NaN/infinity handling for floats.
+    pub non_finite: Option<NonFinite>,
+    /// `duration = "ms"`: parse a `Duration`, with bare numbers in this `DurationUnit` variant.
+    pub duration: Option<syn::Ident>,
+    /// `bytes`: accept byte sizes such as `"1MB"` or `"1 MiB"` on an integer field.
+    pub bytes: bool,
+    /// `percent = "ratio" | "percent"`: accept `"12.5%"` on a float field; the
+    /// `PercentMode` variant says how bare numbers are read.
+    pub percent: Option<syn::Ident>,
+    /// `timestamp = "ms"`: the `TimestampUnit` variant of bare epoch numbers.
+    pub timestamp: Option<syn::Ident>,
+}
+
+impl FieldAttrs {
+    /// Parse all `#[serde_tuplex(...)]` attributes on a field.
+    pub fn from_field(field: &Field) -> syn::Result<Self> {
+        let mut attrs = FieldAttrs::default();
+        let mut has_range = false;
+
+        for attr in &field.attrs {
+            if !attr.path().is_ident("serde_tuplex") {
+                continue;
+            }
+
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    attrs.skip = true;
+                } else if meta.path.is_ident("lenient") {
+                    attrs.lenient = true;
+                } else if meta.path.is_ident("stringify") {
+                    attrs.stringify = true;
+                } else if meta.path.is_ident("radix_prefix") {
+                    attrs.radix_prefix = true;
+                } else if meta.path.is_ident("bytes") {
+                    attrs.bytes = true;
+                } else if meta.path.is_ident("percent") {
+                    let mode = if meta.input.peek(syn::Token![=]) {
+                        let lit: LitStr = meta.value()?.parse()?;
+                        let mode = match lit.value().as_str() {
+                            "ratio" => "Ratio",
+                            "percent" => "Percent",
+                            _ => {
+                                return Err(syn::Error::new_spanned(
+                                    lit,
+                                    "expected \"ratio\" or \"percent\"",
+                                ));
+                            }
+                        };
+                        syn::Ident::new(mode, lit.span())
+                    } else {
+                        syn::Ident::new("Ratio", meta.path.span())
+                    };
+                    attrs.percent = Some(mode);
+                } else if meta.path.is_ident("number_format") {
+                    attrs.number_format = Some(NumberFormatAttr::parse(&meta)?);
+                } else if meta.path.is_ident("normalize") {
+                    attrs.normalize = Some(NormalizeAttr::parse(&meta)?);
+                } else if meta.path.is_ident("serialize") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    attrs.serialize = Some(match lit.value().as_str() {
+                        "hex" => FieldRepr::Hex,
+                        "non_finite_string" => FieldRepr::NonFiniteString,
+                        "human" => FieldRepr::Human,
+                        "iso8601" => FieldRepr::Iso8601,
+                        "si" => FieldRepr::Si,
+                        "percent" => FieldRepr::Percent,
+                        "epoch" => FieldRepr::Epoch,
+                        "string" => FieldRepr::String,
+                        "number" => FieldRepr::Number,
+                        _ => {
+                            return Err(syn::Error::new_spanned(
+                                lit,
+                                "expected \"hex\", \"non_finite_string\", \"human\", \"iso8601\", \"si\", \"percent\", \"epoch\", \"string\" or \"number\"",
+                            ));
+                        }
+                    });
+                } else if meta.path.is_ident("duration") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    let unit = match lit.value().as_str() {
+                        "ns" => "Nanos",
+                        "us" => "Micros",
+                        "ms" => "Millis",
+                        "s" => "Secs",
+                        "m" => "Mins",
+                        "h" => "Hours",
+                        "d" => "Days",
+                        _ => {
+                            return Err(syn::Error::new_spanned(
+                                lit,
+                                "expected \"ns\", \"us\", \"ms\", \"s\", \"m\", \"h\" or \"d\"",
+                            ));
+                        }
+                    };
+                    attrs.duration = Some(syn::Ident::new(unit, lit.span()));
+                } else if meta.path.is_ident("timestamp") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    let unit = match lit.value().as_str() {
+                        "s" => "Secs",
+                        "ms" => "Millis",
+                        "us" => "Micros",
+                        "ns" => "Nanos",
+                        "auto" => "Auto",
+                        _ => {
+                            return Err(syn::Error::new_spanned(
+                                lit,
+                                "expected \"s\", \"ms\", \"us\", \"ns\" or \"auto\"",
+                            ));
+                        }
+                    };
+                    attrs.timestamp = Some(syn::Ident::new(unit, lit.span()));
+                } else if meta.path.is_ident("non_finite") {
+                    attrs.non_finite = Some(NonFinite::parse(&meta)?);
+                } else if meta.path.is_ident("overflow") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    attrs.overflow = Some(match lit.value().as_str() {
+                        "error" => Overflow::Error,
+                        "saturate" => Overflow::Saturate,
+                        "wrap" => Overflow::Wrap,
+                        _ => {
+                            return Err(syn::Error::new_spanned(
+                                lit,
+                                "expected \"saturate\", \"wrap\" or \"error\"",
+                            ));
+                        }
+                    });
+                } else if meta.path.is_ident("range") {
+                    let expr: Expr = meta.value()?.parse()?;
+                    let Expr::Range(range) = expr else {
+                        return Err(syn::Error::new_spanned(
+                            expr,
+                            "expected a range like `1..=10`",
+                        ));
+                    };
+                    if has_range || !attrs.bounds.is_empty() {
+                        return Err(meta.error("`range` cannot be combined with `min`/`max`"));
+                    }
+                    has_range = true;
+                    attrs.bounds = Bounds {
+                        start: range.start.map(|e| *e),
+                        end: range.end.map(|e| *e),
+                        inclusive: matches!(range.limits, RangeLimits::Closed(_)),
+                    };
+                } else if meta.path.is_ident("parse_with") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    attrs.parse_with = Some(lit.parse()?);
+                } else if meta.path.is_ident("true_values") {
+                    attrs.true_values = Some(parse_str_list(meta.value()?)?);
+                } else if meta.path.is_ident("false_values") {
+                    attrs.false_values = Some(parse_str_list(meta.value()?)?);
+                } else if meta.path.is_ident("min") {
+                    if has_range {
+                        return Err(meta.error("`range` cannot be combined with `min`/`max`"));
+                    }
+                    attrs.bounds.start = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("max") {
+                    if has_range {
+                        return Err(meta.error("`range` cannot be combined with `min`/`max`"));
+                    }
+                    attrs.bounds.end = Some(meta.value()?.parse()?);
+                    attrs.bounds.inclusive = true;
+                } else {
+                    return Err(meta.error("unknown serde_tuplex attribute"));
+                }
+                Ok(())
+            })?;
+        }
+
+        attrs.validate(field)?;
+        Ok(attrs)
+    }
+
+    fn validate(&self, field: &Field) -> syn::Result<()> {
+        if self.parse_with.is_some() {
+            if self.skip {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "`parse_with` cannot be combined with `skip`",
+                ));
+            }
+            if self.overflow.is_some() {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "`parse_with` cannot be combined with `overflow`",
+                ));
+            }
+        }
+        if self.radix_prefix {
+            if !is_base_integer(value_type(&field.ty)) {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "`radix_prefix` requires a primitive integer type",
+                ));
+            }
+            if self.skip || self.parse_with.is_some() || self.overflow.is_some() {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "`radix_prefix` cannot be combined with `skip`, `parse_with` or `overflow`",
+                ));
+            }
+        }
+        if self.serialize == Some(FieldRepr::Hex)
+            && !(is_base_integer(value_type(&field.ty)) || self.is_big_integer(field))
+        {
+            return Err(syn::Error::new_spanned(
+                &field.ty,
+                "`serialize = \"hex\"` requires an integer type",
+            ));
+        }
+        if self.serialize == Some(FieldRepr::NonFiniteString)
+            && !is_base_float(value_type(&field.ty))
         {
-            if let Ok(meta_list) = attr.meta.require_list() {
-                for token in meta_list.tokens.clone().into_iter() {
-                    if let proc_macro2::TokenTree::Ident(ident) = token
-                        && ident == "skip"
-                    {
-                        return Some(false);
+            return Err(syn::Error::new_spanned(
+                &field.ty,
+                "`serialize = \"non_finite_string\"` requires an `f32` or `f64` field",
+            ));
+        }
+        if self.bytes {
+            if !is_base_integer(value_type(&field.ty)) {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "`bytes` requires a primitive integer type",
+                ));
+            }
+            if self.skip
+                || self.parse_with.is_some()
+                || self.overflow.is_some()
+                || self.radix_prefix
+                || self.number_format.is_some()
+            {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "`bytes` cannot be combined with `skip`, `parse_with`, `overflow`, `radix_prefix` or `number_format`",
+                ));
+            }
+        }
+        match self.serialize {
+            Some(FieldRepr::Human) if !(self.bytes || is_duration_type(&field.ty)) => {
+                return Err(other_duration_error(&field.ty).unwrap_or_else(|| {
+                    syn::Error::new_spanned(
+                        &field.ty,
+                        "`serialize = \"human\"` requires a `Duration` or `bytes` field",
+                    )
+                }));
+            }
+            Some(FieldRepr::Iso8601) if !is_duration_type(&field.ty) => {
+                return Err(other_duration_error(&field.ty).unwrap_or_else(|| {
+                    syn::Error::new_spanned(
+                        &field.ty,
+                        "`serialize = \"iso8601\"` requires a `Duration` field",
+                    )
+                }));
+            }
+            Some(FieldRepr::Si) if !self.bytes => {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "`serialize = \"si\"` requires a `bytes` field",
+                ));
+            }
+            Some(FieldRepr::Percent) if self.percent.is_none() => {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "`serialize = \"percent\"` requires a `percent` field",
+                ));
+            }
+            Some(FieldRepr::String | FieldRepr::Number) if !self.is_numeric(field) => {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "`serialize = \"string\"` and `\"number\"` require a numeric field",
+                ));
+            }
+            Some(FieldRepr::Epoch) if !is_timestamp_type(&field.ty) => {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "`serialize = \"epoch\"` requires a `DateTime<Utc>` (feature `chrono`) or `OffsetDateTime` (feature `time`) field",
+                ));
+            }
+            _ => {}
+        }
+        if self.timestamp.is_some() {
+            if !is_timestamp_type(&field.ty) {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "`timestamp` requires a `DateTime<Utc>` (feature `chrono`) or `OffsetDateTime` (feature `time`) field",
+                ));
+            }
+            if self.skip || self.parse_with.is_some() {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "`timestamp` cannot be combined with `skip` or `parse_with`",
+                ));
+            }
+        }
+        if self.percent.is_some() {
+            if !is_base_float(value_type(&field.ty)) {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "`percent` requires an `f32` or `f64` field",
+                ));
+            }
+            if self.skip
+                || self.parse_with.is_some()
+                || self.overflow.is_some()
+                || self.number_format.is_some()
+            {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "`percent` cannot be combined with `skip`, `parse_with`, `overflow` or `number_format`",
+                ));
+            }
+        }
+        if self.duration.is_some() {
+            if !is_duration_type(&field.ty) {
+                return Err(other_duration_error(&field.ty).unwrap_or_else(|| {
+                    syn::Error::new_spanned(
+                        &field.ty,
+                        "`duration` requires a `Duration` or `Option<Duration>` field",
+                    )
+                }));
+            }
+            if self.skip || self.parse_with.is_some() {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "`duration` cannot be combined with `skip` or `parse_with`",
+                ));
+            }
+        }
+        if let Some(non_finite) = self.non_finite {
+            if !is_base_float(value_type(&field.ty)) {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "`non_finite` requires an `f32` or `f64` field",
+                ));
+            }
+            if non_finite == NonFinite::Null && !is_option_type(&field.ty) {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "`non_finite = \"null\"` requires an `Option` field",
+                ));
+            }
+            if self.skip {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "`non_finite` cannot be combined with `skip`",
+                ));
+            }
+        }
+        if self.number_format.is_some() {
+            if !(is_numeric_type(&field.ty) || self.lenient || self.parse_with.is_some()) {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "`number_format` requires a numeric type, `lenient` or `parse_with`",
+                ));
+            }
+            if self.skip {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "`number_format` cannot be combined with `skip`",
+                ));
+            }
+        }
+        if self.normalize.is_some() && !self.is_lenient_type(field) {
+            return Err(syn::Error::new_spanned(
+                &field.ty,
+                "`normalize` requires a numeric, `bool` or lenient field",
+            ));
+        }
+        if self.stringify {
+            if !is_string_type(&field.ty) {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "`stringify` requires a `String`, `Option<String>` or `Box<str>` field",
+                ));
+            }
+            if self.skip || self.parse_with.is_some() {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "`stringify` cannot be combined with `skip` or `parse_with`",
+                ));
+            }
+        }
+        if self.true_values.is_some() || self.false_values.is_some() {
+            if !is_bool_type(&field.ty) {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "`true_values`/`false_values` require a `bool` field",
+                ));
+            }
+            if self.skip || self.parse_with.is_some() {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "`true_values`/`false_values` cannot be combined with `skip` or `parse_with`",
+                ));
+            }
+            // A list left out (or empty) keeps the default vocabulary of its side.
+            let words = |values: &Option<Vec<LitStr>>, defaults: &[&str]| match values {
+                Some(values) if !values.is_empty() => values.iter().map(LitStr::value).collect(),
+                _ => defaults.iter().map(|s| s.to_string()).collect::<Vec<_>>(),
+            };
+            let true_words = words(&self.true_values, DEFAULT_TRUE_VALUES);
+            let false_words = words(&self.false_values, DEFAULT_FALSE_VALUES);
+            for lit in self.true_values.iter().chain(&self.false_values).flatten() {
+                let value = lit.value();
+                let contains =
+                    |words: &[String]| words.iter().any(|w| w.eq_ignore_ascii_case(&value));
+                if !(contains(&true_words) && contains(&false_words)) {
+                    continue;
+                }
+                let message = match (&self.true_values, &self.false_values) {
+                    (Some(t), Some(f)) if !t.is_empty() && !f.is_empty() => {
+                        "value appears in both `true_values` and `false_values`"
                     }
+                    (Some(t), _) if !t.is_empty() => {
+                        "value is also a default `false` string; set `false_values` as well"
+                    }
+                    _ => "value is also a default `true` string; set `true_values` as well",
+                };
+                return Err(syn::Error::new_spanned(lit, message));
+            }
+        }
+        if let Some(overflow) = self.overflow {
+            let ty = value_type(&field.ty);
+            if self.skip {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "`overflow` cannot be combined with `skip`",
+                ));
+            }
+            if !is_base_numeric(ty) {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "`overflow` requires a primitive numeric type",
+                ));
+            }
+            if overflow == Overflow::Wrap && is_base_float(ty) {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "`overflow = \"wrap\"` is not supported for floats",
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether any attribute that only `Lenient`/`TupleLenient` understand is set.
+    pub fn has_lenient_options(&self) -> bool {
+        self.lenient
+            || self.stringify
+            || self.radix_prefix
+            || self.number_format.is_some()
+            || self.normalize.is_some()
+            || self.non_finite.is_some()
+            || self.duration.is_some()
+            || self.bytes
+            || self.percent.is_some()
+            || self.timestamp.is_some()
+            || self.overflow.is_some()
+            || self.parse_with.is_some()
+            || self.true_values.is_some()
+            || self.false_values.is_some()
+    }
+
+    /// Apply struct-level defaults to a field that does not override them.
+    pub fn apply_container(&mut self, container: &ContainerAttrs, field: &Field) {
+        if container.radix_prefix
+            && !self.skip
+            && !self.bytes
+            && self.parse_with.is_none()
+            && self.overflow.is_none()
+            && is_base_integer(value_type(&field.ty))
+        {
+            self.radix_prefix = true;
+        }
+        if self.number_format.is_none()
+            && !self.skip
+            && !self.bytes
+            && self.percent.is_none()
+            && self.parse_with.is_none()
+            && self.is_numeric(field)
+        {
+            self.number_format = container.number_format.clone();
+        }
+        if self.normalize.is_none() && self.is_lenient_type(field) {
+            self.normalize = container.normalize.clone();
+        }
+        if self.non_finite.is_none() && !self.skip && is_base_float(value_type(&field.ty)) {
+            // `null` needs somewhere to put it; plain float fields reject instead.
+            self.non_finite = match container.non_finite {
+                Some(NonFinite::Null) if !is_option_type(&field.ty) => Some(NonFinite::Reject),
+                other => other,
+            };
+        }
+    }
+
+    /// Whether the field is numeric, counting bare `Decimal`/`BigInt`/`U256` names marked
+    /// `lenient`.
+    fn is_numeric(&self, field: &Field) -> bool {
+        let ty = value_type(&field.ty);
+        is_numeric_type(ty)
+            || (self.lenient && (is_decimal_type(ty, true) || is_big_integer_type(ty, true)))
+    }
+
+    /// Whether the field is a big integer, by crate path or as a bare name marked `lenient`.
+    pub fn is_big_integer(&self, field: &Field) -> bool {
+        is_big_integer_type(value_type(&field.ty), self.lenient)
+    }
+
+    /// Whether the field is a decimal, by crate path or as a bare name marked `lenient`.
+    pub fn is_decimal(&self, field: &Field) -> bool {
+        is_decimal_type(value_type(&field.ty), self.lenient)
+    }
+
+    /// Whether the field is lenient without counting `normalize`, which only refines it.
+    fn is_lenient_type(&self, field: &Field) -> bool {
+        !self.skip
+            && (self.lenient
+                || self.stringify
+                || self.parse_with.is_some()
+                || self.duration.is_some()
+                || is_numeric_type(&field.ty)
+                || is_bool_type(&field.ty)
+                || is_timestamp_type(&field.ty))
+    }
+
+    /// Determine if field should use lenient parsing based on type and attributes.
+    pub fn is_lenient(&self, field: &Field) -> bool {
+        if self.skip {
+            return false;
+        }
+        self.has_lenient_options()
+            || is_numeric_type(&field.ty)
+            || is_bool_type(&field.ty)
+            || is_timestamp_type(&field.ty)
+    }
+}
+
+/// Struct-level `#[serde_tuplex(...)]` attributes.
+#[derive(Default)]
+pub struct ContainerAttrs {
+    /// `radix_prefix`: every integer field accepts `0x`/`0o`/`0b` strings.
+    pub radix_prefix: bool,
+    /// `number_format = ...`: default number format for numeric fields.
+    pub number_format: Option<NumberFormatAttr>,
+    /// `normalize = ...`: default string clean-up for lenient fields.
+    pub normalize: Option<NormalizeAttr>,
+    /// `non_finite = ...`: default NaN/infinity handling for float fields.
+    pub non_finite: Option<NonFinite>,
+}
+
+impl ContainerAttrs {
+    /// Parse all `#[serde_tuplex(...)]` attributes on a struct.
+    pub fn from_attrs(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut container = ContainerAttrs::default();
+
+        for attr in attrs {
+            if !attr.path().is_ident("serde_tuplex") {
+                continue;
+            }
+
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("radix_prefix") {
+                    container.radix_prefix = true;
+                } else if meta.path.is_ident("number_format") {
+                    container.number_format = Some(NumberFormatAttr::parse(&meta)?);
+                } else if meta.path.is_ident("normalize") {
+                    container.normalize = Some(NormalizeAttr::parse(&meta)?);
+                } else if meta.path.is_ident("non_finite") {
+                    container.non_finite = Some(NonFinite::parse(&meta)?);
+                } else {
+                    return Err(meta.error("unknown serde_tuplex attribute"));
                 }
+                Ok(())
+            })?;
+        }
+
+        Ok(container)
+    }
+
+    /// Whether any attribute that only `Lenient`/`TupleLenient` understand is set.
+    pub fn has_lenient_options(&self) -> bool {
+        self.radix_prefix
+            || self.number_format.is_some()
+            || self.normalize.is_some()
+            || self.non_finite.is_some()
+    }
+}
+
+/// Parse `["a", "b"]`.
+fn parse_str_list(input: syn::parse::ParseStream) -> syn::Result<Vec<LitStr>> {
+    let content;
+    syn::bracketed!(content in input);
+    let list = content.parse_terminated(|input| input.parse::<LitStr>(), syn::Token![,])?;
+    if list.is_empty() {
+        return Err(content.error("expected at least one string"));
+    }
+    Ok(list.into_iter().collect())
+}
+
+/// How `#[derive(LenientEnum)]` writes a variant.
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+pub enum EnumRepr {
+    #[default]
+    Name,
+    Number,
+}
+
+/// Container-level `#[serde_tuplex(...)]` attributes on a `LenientEnum`.
+#[derive(Default)]
+pub struct EnumAttrs {
+    /// `serialize = "name" | "number"`.
+    pub serialize: EnumRepr,
+    /// `case_insensitive`: match variant names ignoring ASCII case.
+    pub case_insensitive: bool,
+}
+
+impl EnumAttrs {
+    /// Parse all `#[serde_tuplex(...)]` attributes on an enum.
+    pub fn from_attrs(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut enum_attrs = EnumAttrs::default();
+
+        for attr in attrs {
+            if !attr.path().is_ident("serde_tuplex") {
+                continue;
             }
-            return Some(true);
+
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("serialize") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    enum_attrs.serialize = match lit.value().as_str() {
+                        "name" => EnumRepr::Name,
+                        "number" => EnumRepr::Number,
+                        _ => {
+                            return Err(syn::Error::new_spanned(
+                                lit,
+                                "expected \"name\" or \"number\"",
+                            ));
+                        }
+                    };
+                } else if meta.path.is_ident("case_insensitive") {
+                    enum_attrs.case_insensitive = true;
+                } else {
+                    return Err(meta.error("unknown serde_tuplex attribute"));
+                }
+                Ok(())
+            })?;
         }
+
+        Ok(enum_attrs)
     }
-    None
 }
 
-/// Determine if field should use lenient parsing based on type and attributes.
-pub fn should_be_lenient(field: &Field) -> bool {
-    match get_serde_tuplex_attr(field) {
-        Some(true) => true,
-        Some(false) => false,
-        None => is_numeric_type(&field.ty),
+/// Variant-level `#[serde_tuplex(...)]` attributes on a `LenientEnum`.
+pub struct VariantAttrs {
+    /// Name written on serialization: the variant ident or `rename = "..."`.
+    pub name: String,
+    /// `alias = "..."`: extra accepted names, repeatable.
+    pub aliases: Vec<String>,
+}
+
+impl VariantAttrs {
+    /// Parse all `#[serde_tuplex(...)]` attributes on a variant.
+    pub fn from_variant(variant: &syn::Variant) -> syn::Result<Self> {
+        let mut attrs = VariantAttrs {
+            name: variant.ident.to_string(),
+            aliases: Vec::new(),
+        };
+
+        for attr in &variant.attrs {
+            if !attr.path().is_ident("serde_tuplex") {
+                continue;
+            }
+
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    attrs.name = lit.value();
+                } else if meta.path.is_ident("alias") {
+                    let lit: LitStr = meta.value()?.parse()?;
+                    attrs.aliases.push(lit.value());
+                } else {
+                    return Err(meta.error("unknown serde_tuplex attribute"));
+                }
+                Ok(())
+            })?;
+        }
+
+        Ok(attrs)
     }
 }
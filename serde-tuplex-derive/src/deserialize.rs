@@ -1,5 +1,7 @@
 //! Code generation for tuple deserialization (strict parsing).
 
+use crate::analysis::{ContainerAttrs, FieldAttrs};
+use crate::validate::gen_range_check;
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{DeriveInput, Fields};
@@ -29,10 +31,39 @@ pub fn gen_deserialize_impl(input: &DeriveInput) -> Result<TokenStream, syn::Err
         _ => return Err(syn::Error::new_spanned(name, "Tuple only supports structs")),
     };
 
+    if ContainerAttrs::from_attrs(&input.attrs)?.has_lenient_options() {
+        return Err(syn::Error::new_spanned(
+            name,
+            "lenient attributes require TupleLenient",
+        ));
+    }
+
     let field_names: Vec<_> = fields.iter().map(|f| &f.ident).collect();
-    let field_types: Vec<_> = fields.iter().map(|f| &f.ty).collect();
     let field_count = fields.len();
-    let field_indices = 0..field_count;
+
+    let field_deserializers = fields
+        .iter()
+        .enumerate()
+        .map(|(idx, field)| {
+            let attrs = FieldAttrs::from_field(field)?;
+            if attrs.has_lenient_options() {
+                return Err(syn::Error::new_spanned(
+                    field,
+                    "lenient attributes require TupleLenient",
+                ));
+            }
+
+            let field_name = &field.ident;
+            let field_ty = &field.ty;
+            let check = gen_range_check(field, &attrs, field.ident.as_ref().unwrap());
+
+            Ok(quote! {
+                let #field_name = seq.next_element::<#field_ty>()?
+                    .ok_or_else(|| ::serde::de::Error::invalid_length(#idx, &self))?;
+                #check
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
 
     let mut de_generics = generics.clone();
     let de_lifetime = syn::Lifetime::new("'de", proc_macro2::Span::call_site());
@@ -63,10 +94,7 @@ pub fn gen_deserialize_impl(input: &DeriveInput) -> Result<TokenStream, syn::Err
                     where
                         __A: ::serde::de::SeqAccess<'de>,
                     {
-                        #(
-                            let #field_names = seq.next_element::<#field_types>()?
-                                .ok_or_else(|| ::serde::de::Error::invalid_length(#field_indices, &self))?;
-                        )*
+                        #(#field_deserializers)*
 
                         Ok(#name {
                             #(#field_names,)*
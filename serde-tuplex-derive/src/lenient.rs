@@ -1,8 +1,11 @@
 //! Code generation for lenient deserialization.
 
-use crate::analysis::{is_option_type, should_be_lenient};
+use crate::analysis::{
+    ContainerAttrs, FieldAttrs, NonFinite, Overflow, is_option_type, value_type,
+};
+use crate::validate::gen_range_check;
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::{DeriveInput, Fields};
 
 /// Generate `Deserialize` impl with lenient numeric parsing.
@@ -48,10 +51,40 @@ pub fn gen_lenient_deserialize(
     );
     let (de_impl_generics, _, _) = de_generics.split_for_impl();
 
+    let container = ContainerAttrs::from_attrs(&input.attrs)?;
+    let mut attrs = fields
+        .iter()
+        .map(FieldAttrs::from_field)
+        .collect::<syn::Result<Vec<_>>>()?;
+    for (field, attrs) in fields.iter().zip(&mut attrs) {
+        attrs.apply_container(&container, field);
+        // `Lenient` only derives `Deserialize`; the output form belongs to serde's derive.
+        if !as_tuple && attrs.serialize.is_some() {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`serialize` only applies to Tuple and TupleLenient; use `#[serde(serialize_with = \"serde_tuplex::serialize_hex\")]`",
+            ));
+        }
+    }
+
     let deserialize_body = if as_tuple {
-        gen_tuple_visitor(fields, name, &impl_generics, &ty_generics, &where_clause)
+        gen_tuple_visitor(
+            fields,
+            &attrs,
+            name,
+            &impl_generics,
+            &ty_generics,
+            &where_clause,
+        )
     } else {
-        gen_struct_visitor(fields, name, &impl_generics, &ty_generics, &where_clause)
+        gen_struct_visitor(
+            fields,
+            &attrs,
+            name,
+            &impl_generics,
+            &ty_generics,
+            &where_clause,
+        )
     };
 
     Ok(quote! {
@@ -66,9 +99,96 @@ pub fn gen_lenient_deserialize(
     })
 }
 
+/// Expression converting the `LenientValue` in `value` to the field's value type.
+///
+/// Evaluates to `Result<T, String>`.
+fn gen_parse(field: &syn::Field, attrs: &FieldAttrs, value: &syn::Ident) -> TokenStream {
+    let mut parse = gen_parse_step(attrs, value);
+    if attrs.non_finite == Some(NonFinite::Reject) {
+        parse = quote! { #parse.and_then(::serde_tuplex::__private::reject_non_finite) };
+    }
+    let parse = match &attrs.number_format {
+        Some(format) => {
+            let format = format.to_tokens();
+            quote! { #value.normalize_with(&#format).and_then(|#value| #parse) }
+        }
+        None => parse,
+    };
+    // `parse_with` parsers see the raw input unless `normalize` asks otherwise.
+    let steps = match &attrs.normalize {
+        Some(steps) => steps.to_tokens(),
+        None if attrs.parse_with.is_some() => return parse,
+        None => {
+            let ty = value_type(&field.ty);
+            quote! { <#ty as ::serde_tuplex::LenientParse>::NORMALIZE }
+        }
+    };
+    quote! {{
+        let #value = #value.normalized(#steps);
+        #parse
+    }}
+}
+
+/// Statement replacing a non-finite `Option` float in `ident` with `None`, for
+/// `non_finite = "null"`.
+fn gen_non_finite_null(attrs: &FieldAttrs, ident: &syn::Ident) -> TokenStream {
+    if attrs.non_finite != Some(NonFinite::Null) {
+        return quote! {};
+    }
+    quote! {
+        let #ident = #ident.filter(|v| v.is_finite());
+    }
+}
+
+/// The conversion itself, after any string normalization.
+fn gen_parse_step(attrs: &FieldAttrs, value: &syn::Ident) -> TokenStream {
+    if let Some(parser) = &attrs.parse_with {
+        return quote! { ::serde_tuplex::__private::parse_field_with(&#parser, &#value) };
+    }
+
+    if attrs.bytes {
+        return quote! { #value.parse_bytes_exact() };
+    }
+
+    if let Some(unit) = &attrs.duration {
+        return quote! { #value.parse_duration_exact(::serde_tuplex::DurationUnit::#unit) };
+    }
+
+    if let Some(mode) = &attrs.percent {
+        return quote! { #value.parse_percent_exact(::serde_tuplex::PercentMode::#mode) };
+    }
+
+    if let Some(unit) = &attrs.timestamp {
+        return quote! { #value.parse_timestamp_exact(::serde_tuplex::TimestampUnit::#unit) };
+    }
+
+    if attrs.true_values.is_some() || attrs.false_values.is_some() {
+        let true_values = attrs.true_values.iter().flatten();
+        let false_values = attrs.false_values.iter().flatten();
+        return quote! {
+            ::serde_tuplex::__private::parse_bool_with(
+                &#value,
+                &[#(#true_values),*],
+                &[#(#false_values),*],
+            )
+        };
+    }
+
+    if attrs.radix_prefix {
+        return quote! { #value.parse_radix_exact() };
+    }
+
+    match attrs.overflow {
+        Some(Overflow::Saturate) => quote! { #value.parse_saturating() },
+        Some(Overflow::Wrap) => quote! { #value.parse_wrapping() },
+        Some(Overflow::Error) | None => quote! { #value.parse_exact() },
+    }
+}
+
 /// Generate visitor for tuple format with lenient parsing.
 fn gen_tuple_visitor(
     fields: &syn::punctuated::Punctuated<syn::Field, syn::token::Comma>,
+    attrs: &[FieldAttrs],
     name: &syn::Ident,
     impl_generics: &syn::ImplGenerics,
     ty_generics: &syn::TypeGenerics,
@@ -79,26 +199,27 @@ fn gen_tuple_visitor(
 
     let tuple_field_deserializers: Vec<_> = fields
         .iter()
+        .zip(attrs)
         .enumerate()
-        .map(|(idx, field)| {
+        .map(|(idx, (field, attrs))| {
             let field_name = &field.ident;
             let field_ty = &field.ty;
+            let parse = gen_parse(field, attrs, &format_ident!("__lenient_value"));
 
-            if should_be_lenient(field) {
+            let deserialize = if attrs.is_lenient(field) {
                 if is_option_type(&field.ty) {
                     quote! {
-                        let #field_name = seq.next_element::<::serde_tuplex::__private::OptionalLenientValue>()?
+                        let #field_name: #field_ty = seq.next_element::<::std::option::Option<::serde_tuplex::__private::LenientValue<'de>>>()?
                             .ok_or_else(|| ::serde::de::Error::invalid_length(#idx, &self))?
-                            .into_option()
-                            .map(|v| v.parse())
+                            .map(|__lenient_value| #parse)
                             .transpose()
                             .map_err(|e: String| ::serde::de::Error::custom(e))?;
                     }
                 } else {
                     quote! {
-                        let #field_name = seq.next_element::<::serde_tuplex::__private::LenientValue>()?
-                            .ok_or_else(|| ::serde::de::Error::invalid_length(#idx, &self))?
-                            .parse::<#field_ty>()
+                        let __lenient_value = seq.next_element::<::serde_tuplex::__private::LenientValue<'de>>()?
+                            .ok_or_else(|| ::serde::de::Error::invalid_length(#idx, &self))?;
+                        let #field_name: #field_ty = #parse
                             .map_err(|e: String| ::serde::de::Error::custom(e))?;
                     }
                 }
@@ -107,6 +228,14 @@ fn gen_tuple_visitor(
                     let #field_name = seq.next_element::<#field_ty>()?
                         .ok_or_else(|| ::serde::de::Error::invalid_length(#idx, &self))?;
                 }
+            };
+            let null = gen_non_finite_null(attrs, field.ident.as_ref().unwrap());
+            let check = gen_range_check(field, attrs, field.ident.as_ref().unwrap());
+
+            quote! {
+                #deserialize
+                #null
+                #check
             }
         })
         .collect();
@@ -147,6 +276,7 @@ fn gen_tuple_visitor(
 /// Generate visitor for struct format with lenient parsing.
 fn gen_struct_visitor(
     fields: &syn::punctuated::Punctuated<syn::Field, syn::token::Comma>,
+    attrs: &[FieldAttrs],
     name: &syn::Ident,
     impl_generics: &syn::ImplGenerics,
     ty_generics: &syn::TypeGenerics,
@@ -163,32 +293,40 @@ fn gen_struct_visitor(
 
     let field_deserializers: Vec<_> = fields
         .iter()
-        .map(|field| {
+        .zip(attrs)
+        .map(|(field, attrs)| {
             let field_name = &field.ident;
             let field_ty = &field.ty;
+            let parse = gen_parse(field, attrs, &format_ident!("__lenient_value"));
 
-            if should_be_lenient(field) {
+            let deserialize = if attrs.is_lenient(field) {
                 if is_option_type(&field.ty) {
                     quote! {
-                        let __lenient_value = map.next_value::<::serde_tuplex::__private::OptionalLenientValue>()?;
-                        let parsed: #field_ty = __lenient_value.into_option()
-                            .map(|v| v.parse())
+                        let __value: #field_ty = map.next_value::<::std::option::Option<::serde_tuplex::__private::LenientValue<'de>>>()?
+                            .map(|__lenient_value| #parse)
                             .transpose()
                             .map_err(|e: String| ::serde::de::Error::custom(format!("failed to parse field {}: {}", stringify!(#field_name), e)))?;
-                        #field_name = Some(parsed);
                     }
                 } else {
                     quote! {
-                        let __lenient_value = map.next_value::<::serde_tuplex::__private::LenientValue>()?;
-                        let __parsed_value = __lenient_value.parse::<#field_ty>()
-                            .map_err(|e| ::serde::de::Error::custom(format!("failed to parse field {}: {}", stringify!(#field_name), e)))?;
-                        #field_name = Some(__parsed_value);
+                        let __lenient_value = map.next_value::<::serde_tuplex::__private::LenientValue<'de>>()?;
+                        let __value: #field_ty = #parse
+                            .map_err(|e: String| ::serde::de::Error::custom(format!("failed to parse field {}: {}", stringify!(#field_name), e)))?;
                     }
                 }
             } else {
                 quote! {
-                    #field_name = Some(map.next_value::<#field_ty>()?);
+                    let __value = map.next_value::<#field_ty>()?;
                 }
+            };
+            let null = gen_non_finite_null(attrs, &format_ident!("__value"));
+            let check = gen_range_check(field, attrs, &format_ident!("__value"));
+
+            quote! {
+                #deserialize
+                #null
+                #check
+                #field_name = Some(__value);
             }
         })
         .collect();
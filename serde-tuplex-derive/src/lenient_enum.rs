@@ -0,0 +1,158 @@
+//! Code generation for `#[derive(LenientEnum)]`.
+
+use crate::analysis::{EnumAttrs, EnumRepr, VariantAttrs};
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{DeriveInput, Fields};
+
+/// Generate `Serialize` and `Deserialize` impls for a unit-only enum.
+pub fn gen_lenient_enum(input: &DeriveInput) -> Result<TokenStream, syn::Error> {
+    let name = &input.ident;
+
+    let syn::Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            name,
+            "LenientEnum only supports enums",
+        ));
+    };
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "LenientEnum does not support generic enums",
+        ));
+    }
+    if data.variants.is_empty() {
+        return Err(syn::Error::new_spanned(
+            name,
+            "LenientEnum requires at least one variant",
+        ));
+    }
+    if let Some(variant) = data
+        .variants
+        .iter()
+        .find(|v| !matches!(v.fields, Fields::Unit))
+    {
+        return Err(syn::Error::new_spanned(
+            variant,
+            "LenientEnum only supports unit variants",
+        ));
+    }
+
+    let enum_attrs = EnumAttrs::from_attrs(&input.attrs)?;
+    let variant_attrs = data
+        .variants
+        .iter()
+        .map(VariantAttrs::from_variant)
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    // Every accepted string must select exactly one variant.
+    let mut seen = Vec::new();
+    for (variant, attrs) in data.variants.iter().zip(&variant_attrs) {
+        for accepted in std::iter::once(&attrs.name).chain(&attrs.aliases) {
+            let key = if enum_attrs.case_insensitive {
+                accepted.to_ascii_lowercase()
+            } else {
+                accepted.clone()
+            };
+            if seen.contains(&key) {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    format!("`{}` is accepted by more than one variant", accepted),
+                ));
+            }
+            seen.push(key);
+        }
+    }
+
+    let idents: Vec<_> = data.variants.iter().map(|v| &v.ident).collect();
+    // Discriminants travel as `i128`; only a `u128` repr can hold values past its range.
+    let fits_i128 = if is_repr_u128(input)? {
+        let checks = data.variants.iter().map(|variant| {
+            let ident = &variant.ident;
+            quote_spanned! {variant.span()=>
+                ::std::assert!(
+                    #name::#ident as u128 <= i128::MAX as u128,
+                    "LenientEnum discriminants must fit in i128",
+                );
+            }
+        });
+        quote! { const _: () = { #(#checks)* }; }
+    } else {
+        quote! {}
+    };
+    let names: Vec<_> = variant_attrs.iter().map(|a| &a.name).collect();
+    let aliases: Vec<_> = variant_attrs.iter().map(|a| &a.aliases).collect();
+    let indices = 0..idents.len();
+    let case_insensitive = enum_attrs.case_insensitive;
+
+    let serialize_body = match enum_attrs.serialize {
+        EnumRepr::Name => quote! {
+            match self {
+                #( #name::#idents => serializer.serialize_str(#names), )*
+            }
+        },
+        EnumRepr::Number => quote! {
+            let discriminant = match self {
+                #( #name::#idents => #name::#idents as i128, )*
+            };
+            ::serde_tuplex::__private::serialize_discriminant(discriminant, serializer)
+        },
+    };
+
+    Ok(quote! {
+        #fits_i128
+
+        impl ::serde::Serialize for #name {
+            fn serialize<__S>(&self, serializer: __S) -> ::std::result::Result<__S::Ok, __S::Error>
+            where
+                __S: ::serde::Serializer,
+            {
+                #serialize_body
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for #name {
+            fn deserialize<__D>(deserializer: __D) -> ::std::result::Result<Self, __D::Error>
+            where
+                __D: ::serde::Deserializer<'de>,
+            {
+                const VARIANTS: &[::serde_tuplex::__private::EnumVariant] = &[
+                    #(
+                        ::serde_tuplex::__private::EnumVariant {
+                            name: #names,
+                            aliases: &[#(#aliases),*],
+                            discriminant: #name::#idents as i128,
+                        },
+                    )*
+                ];
+
+                let value = <::serde_tuplex::__private::LenientValue<'de> as ::serde::Deserialize>::deserialize(deserializer)?;
+                let index = ::serde_tuplex::__private::match_enum_variant(
+                    &value,
+                    stringify!(#name),
+                    VARIANTS,
+                    #case_insensitive,
+                )
+                .map_err(::serde::de::Error::custom)?;
+                match index {
+                    #( #indices => Ok(#name::#idents), )*
+                    _ => unreachable!(),
+                }
+            }
+        }
+    })
+}
+
+/// Whether the enum is `#[repr(u128)]`.
+fn is_repr_u128(input: &DeriveInput) -> syn::Result<bool> {
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("repr")) {
+        let reprs = attr.parse_args_with(
+            syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated,
+        )?;
+        if reprs.iter().any(|repr| repr.path().is_ident("u128")) {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
@@ -5,7 +5,9 @@
 mod analysis;
 mod deserialize;
 mod lenient;
+mod lenient_enum;
 mod serialize;
+mod validate;
 
 use proc_macro::TokenStream;
 use quote::quote;
@@ -21,7 +23,9 @@ use syn::parse_macro_input;
 ///
 /// // Serializes as [10,20]
 /// ```
-#[proc_macro_derive(Tuple)]
+///
+/// Fields accept `#[serde_tuplex(range = 1..=10)]`, `min` and `max` checks.
+#[proc_macro_derive(Tuple, attributes(serde_tuplex))]
 pub fn derive_tuple(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as syn::DeriveInput);
 
@@ -45,8 +49,20 @@ pub fn derive_tuple(input: TokenStream) -> TokenStream {
 
 /// Accept both `123` and `"123"` for numeric fields.
 ///
-/// Numeric fields (integers, floats, `Option<numeric>`) automatically get lenient parsing.
-/// Use `#[serde_tuplex(skip)]` to disable for specific fields.
+/// Numeric fields and `bool`, plus their `Option`s, get lenient parsing automatically; use
+/// `#[serde_tuplex(skip)]` to disable it for a field. Numeric covers the primitives,
+/// `NonZero*`/`Wrapping`/`Saturating` integers and, with their crate features, decimals, big
+/// integers and timestamps. Other field attributes, each `#[serde_tuplex(...)]`:
+///
+/// - `lenient`, `parse_with = "path"` - any `LenientParse` type, or a custom parser
+/// - `range = a..=b`, `min`, `max`, `overflow = "saturate"` - bounds and out-of-range input
+/// - `radix_prefix`, `number_format = "de"`, `normalize(...)`, `non_finite = "reject"` - number
+///   input, also settable on the struct
+/// - `true_values`, `false_values`, `stringify` - `bool` and string fields
+/// - `duration = "ms"`, `bytes`, `percent`, `timestamp = "ms"` - values with units
+///
+/// See [field attributes](https://docs.rs/serde-tuplex/latest/serde_tuplex/#field-attributes)
+/// in the crate docs for each.
 ///
 /// ```
 /// use serde_tuplex::Lenient;
@@ -85,6 +101,10 @@ pub fn derive_lenient(input: TokenStream) -> TokenStream {
 ///
 /// // Accepts [123, 4.5] or ["123", "4.5"]
 /// ```
+///
+/// `#[serde_tuplex(serialize = "...")]` picks a field's output form, such as `"hex"`,
+/// `"human"` or `"string"`; see
+/// [serialization](https://docs.rs/serde-tuplex/latest/serde_tuplex/#serialization).
 #[proc_macro_derive(TupleLenient, attributes(serde_tuplex))]
 pub fn derive_tuple_lenient(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as syn::DeriveInput);
@@ -106,3 +126,33 @@ pub fn derive_tuple_lenient(input: TokenStream) -> TokenStream {
 
     TokenStream::from(combined)
 }
+
+/// Accept a unit enum as its discriminant (`1`, `"1"`) or its name (`"Active"`).
+///
+/// Serializes as the name by default; `#[serde_tuplex(serialize = "number")]` writes the
+/// discriminant instead. `#[serde_tuplex(case_insensitive)]` matches names ignoring ASCII
+/// case. Variants take `rename = "..."` and repeatable `alias = "..."`. Discriminants must
+/// fit in `i128`, so a `#[repr(u128)]` enum with larger values does not compile.
+///
+/// ```
+/// use serde_tuplex::LenientEnum;
+///
+/// #[derive(LenientEnum)]
+/// #[serde_tuplex(case_insensitive)]
+/// enum Status {
+///     Inactive = 0,
+///     #[serde_tuplex(alias = "enabled")]
+///     Active = 1,
+/// }
+///
+/// // Accepts 1, "1", "Active", "ACTIVE" or "enabled"; serializes as "Active"
+/// ```
+#[proc_macro_derive(LenientEnum, attributes(serde_tuplex))]
+pub fn derive_lenient_enum(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+
+    match lenient_enum::gen_lenient_enum(&input) {
+        Ok(tokens) => TokenStream::from(tokens),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
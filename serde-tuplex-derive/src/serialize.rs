@@ -1,5 +1,6 @@
 //! Code generation for tuple serialization.
 
+use crate::analysis::{FieldAttrs, FieldRepr, is_option_type};
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{DeriveInput, Fields};
@@ -23,8 +24,130 @@ pub fn gen_serialize_impl(input: &DeriveInput) -> Result<TokenStream, syn::Error
         _ => return Err(syn::Error::new_spanned(name, "Only supports structs")),
     };
 
-    let field_names: Vec<_> = fields.iter().map(|f| &f.ident).collect();
     let field_count = fields.len();
+    let elements = fields
+        .iter()
+        .map(|field| {
+            let attrs = FieldAttrs::from_field(field)?;
+            let field_name = &field.ident;
+            let units = match attrs.serialize {
+                Some(FieldRepr::Human) if attrs.bytes => Some(quote! { Iec }),
+                Some(FieldRepr::Si) => Some(quote! { Si }),
+                _ => None,
+            };
+            if let Some(units) = units {
+                let wrapper = if is_option_type(&field.ty) {
+                    quote! { ByteSizeOption }
+                } else {
+                    quote! { ByteSize }
+                };
+                return Ok(quote! {
+                    &::serde_tuplex::__private::#wrapper(
+                        &self.#field_name,
+                        ::serde_tuplex::__private::ByteUnits::#units,
+                    )
+                });
+            }
+            let duration = match (attrs.serialize, &attrs.duration) {
+                (Some(FieldRepr::Human), _) => Some(quote! { Human }),
+                (Some(FieldRepr::Iso8601), _) => Some(quote! { Iso8601 }),
+                (_, Some(unit)) => Some(quote! { Number(::serde_tuplex::DurationUnit::#unit) }),
+                _ => None,
+            };
+            if let Some(repr) = duration {
+                return Ok(quote! {
+                    &::serde_tuplex::__private::DurationField(
+                        &self.#field_name,
+                        ::serde_tuplex::__private::DurationRepr::#repr,
+                    )
+                });
+            }
+            if attrs.serialize == Some(FieldRepr::Epoch) {
+                let wrapper = if is_option_type(&field.ty) {
+                    quote! { EpochOption }
+                } else {
+                    quote! { Epoch }
+                };
+                let unit = match &attrs.timestamp {
+                    Some(unit) => quote! { #unit },
+                    None => quote! { Secs },
+                };
+                return Ok(quote! {
+                    &::serde_tuplex::__private::#wrapper(
+                        &self.#field_name,
+                        ::serde_tuplex::TimestampUnit::#unit,
+                    )
+                });
+            }
+            let percent = match (attrs.serialize, &attrs.percent) {
+                (Some(FieldRepr::Percent), _) => Some(quote! { String }),
+                (_, Some(mode)) if mode == "Percent" => Some(quote! { Number }),
+                _ => None,
+            };
+            if let Some(repr) = percent {
+                let wrapper = if is_option_type(&field.ty) {
+                    quote! { PercentOption }
+                } else {
+                    quote! { Percent }
+                };
+                return Ok(quote! {
+                    &::serde_tuplex::__private::#wrapper(
+                        &self.#field_name,
+                        ::serde_tuplex::__private::PercentRepr::#repr,
+                    )
+                });
+            }
+            let text = match attrs.serialize {
+                Some(FieldRepr::String) => Some(quote! { String }),
+                Some(FieldRepr::Number) => Some(quote! { Number }),
+                // Big integers and decimals do not fit JSON numbers, and their crates' serde
+                // support is optional; write their digits as a string.
+                None if attrs.is_big_integer(field) || attrs.is_decimal(field) => {
+                    Some(quote! { String })
+                }
+                _ => None,
+            };
+            if let Some(repr) = text {
+                let wrapper = if is_option_type(&field.ty) {
+                    quote! { AsTextOption }
+                } else {
+                    quote! { AsText }
+                };
+                return Ok(quote! {
+                    &::serde_tuplex::__private::#wrapper(
+                        &self.#field_name,
+                        ::serde_tuplex::__private::TextRepr::#repr,
+                    )
+                });
+            }
+            Ok(match attrs.serialize {
+                Some(FieldRepr::Hex) if is_option_type(&field.ty) => {
+                    quote! { &::serde_tuplex::__private::HexOption(&self.#field_name) }
+                }
+                Some(FieldRepr::Hex) => {
+                    quote! { &::serde_tuplex::__private::Hex(&self.#field_name) }
+                }
+                Some(FieldRepr::NonFiniteString) if is_option_type(&field.ty) => {
+                    quote! { &::serde_tuplex::__private::NonFiniteStringOption(&self.#field_name) }
+                }
+                Some(FieldRepr::NonFiniteString) => {
+                    quote! { &::serde_tuplex::__private::NonFiniteString(&self.#field_name) }
+                }
+                Some(
+                    FieldRepr::Human
+                    | FieldRepr::Iso8601
+                    | FieldRepr::Si
+                    | FieldRepr::Percent
+                    | FieldRepr::Epoch
+                    | FieldRepr::String
+                    | FieldRepr::Number,
+                )
+                | None => {
+                    quote! { &self.#field_name }
+                }
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
 
     Ok(quote! {
         impl #impl_generics ::serde::Serialize for #name #ty_generics #where_clause {
@@ -35,7 +158,7 @@ pub fn gen_serialize_impl(input: &DeriveInput) -> Result<TokenStream, syn::Error
                 use ::serde::ser::SerializeTuple;
                 let mut tuple = serializer.serialize_tuple(#field_count)?;
                 #(
-                    tuple.serialize_element(&self.#field_names)?;
+                    tuple.serialize_element(#elements)?;
                 )*
                 tuple.end()
             }
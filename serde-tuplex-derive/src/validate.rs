@@ -0,0 +1,52 @@
+//! Code generation for post-parse field validation.
+
+use crate::analysis::{FieldAttrs, is_option_type, value_type};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Field;
+
+/// Generate range checks for a deserialized field bound to `value`.
+///
+/// Produces an empty stream if the field has no `range`/`min`/`max` attribute.
+pub fn gen_range_check(field: &Field, attrs: &FieldAttrs, value: &syn::Ident) -> TokenStream {
+    let bounds = &attrs.bounds;
+    if bounds.is_empty() {
+        return TokenStream::new();
+    }
+
+    let value_ty = value_type(&field.ty);
+    let field_name = field.ident.as_ref().unwrap().to_string();
+    let expected = format!("a value in range {}", bounds.describe());
+
+    let start = match &bounds.start {
+        Some(start) => quote! { ::std::ops::Bound::Included(#start) },
+        None => quote! { ::std::ops::Bound::Unbounded },
+    };
+    let end = match &bounds.end {
+        Some(end) if bounds.inclusive => quote! { ::std::ops::Bound::Included(#end) },
+        Some(end) => quote! { ::std::ops::Bound::Excluded(#end) },
+        None => quote! { ::std::ops::Bound::Unbounded },
+    };
+
+    let check = quote! {
+        ::serde_tuplex::__private::check_range::<#value_ty, _>(
+            #field_name,
+            __checked,
+            (#start, #end),
+            #expected,
+        )?;
+    };
+
+    if is_option_type(&field.ty) {
+        quote! {
+            if let ::std::option::Option::Some(__checked) = &#value {
+                #check
+            }
+        }
+    } else {
+        quote! {
+            let __checked = &#value;
+            #check
+        }
+    }
+}
@@ -0,0 +1,127 @@
+//! Integers wider than `u128` behind the `num-bigint` and `primitive-types` features.
+//!
+//! They parse from native numbers, decimal strings (also `"1.5e30"` when integral) and
+//! `0x`/`0o`/`0b` strings, and implement [`RadixParse`] so `serialize = "hex"` works too.
+
+use crate::normalize::Normalize;
+use crate::parse::{LenientParse, RadixParse, integral_text, invalid_str, split_radix};
+
+/// Sign, radix and digits of `s`, with decimal and exponent notation reduced to integer digits.
+fn big_digits(s: &str, ty: &str) -> Result<(bool, u32, String), String> {
+    match split_radix(s) {
+        Ok((_, 10, digits)) if !digits.bytes().all(|b| b.is_ascii_digit()) => {}
+        Ok(parts) => return Ok(parts),
+        Err(_) if s.contains('.') => {}
+        Err(e) => return Err(invalid_str(s, ty, e)),
+    }
+    let text = integral_text(s, ty)?;
+    Ok(match text.strip_prefix('-') {
+        Some(digits) => (true, 10, digits.to_string()),
+        None => (false, 10, text),
+    })
+}
+
+macro_rules! impl_lenient_parse_big {
+    ($ty:ty) => {
+        impl LenientParse for $ty {
+            const NORMALIZE: Normalize = Normalize::DEFAULT;
+
+            fn parse_f64(v: f64) -> Result<Self, String> {
+                if !v.is_finite() {
+                    return Err(format!("{} is not an integer", v));
+                }
+                // `Display` for floats never uses an exponent.
+                Self::parse_str(&v.to_string())
+            }
+
+            fn parse_bool(v: bool) -> Result<Self, String> {
+                Err(format!("{} is not an integer", v))
+            }
+
+            fn parse_str(s: &str) -> Result<Self, String> {
+                Self::parse_radix_str(s)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "num-bigint")]
+fn parse_biguint(s: &str, ty: &str) -> Result<(bool, num_bigint::BigUint), String> {
+    let (negative, radix, digits) = big_digits(s, ty)?;
+    let magnitude = num_bigint::BigUint::parse_bytes(digits.as_bytes(), radix)
+        .ok_or_else(|| invalid_str(s, ty, "invalid digit found in string"))?;
+    Ok((negative, magnitude))
+}
+
+#[cfg(feature = "num-bigint")]
+impl RadixParse for num_bigint::BigUint {
+    fn parse_radix_str(s: &str) -> Result<Self, String> {
+        match parse_biguint(s, "BigUint")? {
+            (true, magnitude) if magnitude != Self::ZERO => {
+                Err(crate::parse::out_of_range(s, "BigUint"))
+            }
+            (_, magnitude) => Ok(magnitude),
+        }
+    }
+
+    fn to_hex_string(&self) -> String {
+        format!("0x{:x}", self)
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl_lenient_parse_big!(num_bigint::BigUint);
+
+#[cfg(feature = "num-bigint")]
+impl RadixParse for num_bigint::BigInt {
+    fn parse_radix_str(s: &str) -> Result<Self, String> {
+        let (negative, magnitude) = parse_biguint(s, "BigInt")?;
+        let sign = if negative {
+            num_bigint::Sign::Minus
+        } else {
+            num_bigint::Sign::Plus
+        };
+        Ok(Self::from_biguint(sign, magnitude))
+    }
+
+    fn to_hex_string(&self) -> String {
+        match self.sign() {
+            num_bigint::Sign::Minus => format!("-0x{:x}", self.magnitude()),
+            _ => format!("0x{:x}", self.magnitude()),
+        }
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl_lenient_parse_big!(num_bigint::BigInt);
+
+#[cfg(feature = "primitive-types")]
+impl RadixParse for primitive_types::U256 {
+    fn parse_radix_str(s: &str) -> Result<Self, String> {
+        use primitive_types::U256;
+
+        let (negative, radix, digits) = big_digits(s, "U256")?;
+        let out_of_range = || crate::parse::out_of_range(s, "U256");
+        let mut value = U256::zero();
+        for c in digits.chars() {
+            let digit = c
+                .to_digit(radix)
+                .ok_or_else(|| invalid_str(s, "U256", "invalid digit found in string"))?;
+            value = value
+                .checked_mul(U256::from(radix))
+                .and_then(|v| v.checked_add(U256::from(digit)))
+                .ok_or_else(out_of_range)?;
+        }
+        if negative && !value.is_zero() {
+            return Err(out_of_range());
+        }
+        Ok(value)
+    }
+
+    fn to_hex_string(&self) -> String {
+        format!("0x{:x}", self)
+    }
+}
+
+#[cfg(feature = "primitive-types")]
+impl_lenient_parse_big!(primitive_types::U256);
@@ -0,0 +1,114 @@
+//! Byte sizes such as `"1MB"`, `"1 MiB"` or `"512k"`.
+
+use std::fmt::Write;
+
+/// SI multipliers, indexed by prefix position in `"kmgtpe"`.
+const SI: [u128; 6] = [
+    1_000,
+    1_000_000,
+    1_000_000_000,
+    1_000_000_000_000,
+    1_000_000_000_000_000,
+    1_000_000_000_000_000_000,
+];
+
+/// IEC multipliers, indexed like [`SI`].
+const IEC: [u128; 6] = [1 << 10, 1 << 20, 1 << 30, 1 << 40, 1 << 50, 1 << 60];
+
+const PREFIXES: &str = "kmgtpe";
+
+/// Parse `1048576`, `"1MB"`, `"1 MiB"`, `"1.5k"` or `"512KiB"` into bytes.
+///
+/// Suffixes ignore case; `k`/`kb` are SI (1000) and `ki`/`kib` are IEC (1024), up to exa.
+pub(crate) fn parse_byte_size(s: &str) -> Result<u128, String> {
+    let invalid = |reason: &str| format!("invalid byte size {:?}: {}", s, reason);
+    let trimmed = s.trim();
+    let split = trimmed
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(trimmed.len());
+    let (number, suffix) = trimmed.split_at(split);
+    let (int, frac) = number.split_once('.').unwrap_or((number, ""));
+    let is_digits = |d: &str| d.bytes().all(|b| b.is_ascii_digit());
+    if int.is_empty() && frac.is_empty() || !is_digits(int) || !is_digits(frac) {
+        return Err(invalid("expected a number"));
+    }
+
+    let multiplier = suffix_multiplier(suffix.trim_start())
+        .ok_or_else(|| invalid("unknown unit; expected B, kB, KiB, MB, MiB, ..."))?;
+    let too_large = || invalid("too large");
+    let whole = int
+        .parse::<u128>()
+        .map_err(|_| too_large())?
+        .checked_mul(multiplier)
+        .ok_or_else(too_large)?;
+    let frac = frac.trim_end_matches('0');
+    if frac.is_empty() {
+        return Ok(whole);
+    }
+    let scale = u32::try_from(frac.len())
+        .ok()
+        .and_then(|len| 10u128.checked_pow(len))
+        .ok_or_else(|| invalid("fractional bytes"))?;
+    let part = frac
+        .parse::<u128>()
+        .ok()
+        .and_then(|f| f.checked_mul(multiplier))
+        .ok_or_else(too_large)?;
+    if part % scale != 0 {
+        return Err(invalid("fractional bytes"));
+    }
+    whole.checked_add(part / scale).ok_or_else(too_large)
+}
+
+fn suffix_multiplier(suffix: &str) -> Option<u128> {
+    let suffix = suffix.to_ascii_lowercase();
+    if suffix.is_empty() || suffix == "b" {
+        return Some(1);
+    }
+    let mut chars = suffix.chars();
+    let index = PREFIXES.find(chars.next()?)?;
+    match chars.as_str() {
+        "" | "b" => Some(SI[index]),
+        "i" | "ib" => Some(IEC[index]),
+        _ => None,
+    }
+}
+
+/// Unit family for human-readable byte sizes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteUnits {
+    /// `KiB`, `MiB`, ... (powers of 1024).
+    Iec,
+    /// `kB`, `MB`, ... (powers of 1000).
+    Si,
+}
+
+/// The largest unit that keeps `bytes` exact within three decimals, e.g. `"1.5KiB"`;
+/// otherwise plain bytes such as `"1025B"`.
+pub(crate) fn format_byte_size(bytes: u128, units: ByteUnits) -> String {
+    let (multipliers, names) = match units {
+        ByteUnits::Iec => (IEC, ["KiB", "MiB", "GiB", "TiB", "PiB", "EiB"]),
+        ByteUnits::Si => (SI, ["kB", "MB", "GB", "TB", "PB", "EB"]),
+    };
+    for (multiplier, name) in multipliers.iter().zip(names).rev() {
+        if bytes < *multiplier {
+            continue;
+        }
+        let Some(thousandths) = bytes.checked_mul(1000) else {
+            continue;
+        };
+        if thousandths % multiplier != 0 {
+            continue;
+        }
+        let thousandths = thousandths / multiplier;
+        let mut out = (thousandths / 1000).to_string();
+        let frac = format!("{:03}", thousandths % 1000);
+        let frac = frac.trim_end_matches('0');
+        if !frac.is_empty() {
+            let _ = write!(out, ".{}", frac);
+        }
+        out.push_str(name);
+        return out;
+    }
+    format!("{}B", bytes)
+}
@@ -1,9 +1,12 @@
-use crate::internal::LenientValue;
+use crate::parse::{LenientParse, RadixParse};
+use crate::percent::PercentMode;
+use crate::value::LenientValue;
 use serde::{Deserialize, Deserializer};
+use std::time::Duration;
 
 /// Deserialize numeric values that accept both numbers and strings.
 ///
-/// Use with `#[serde(deserialize_with = "lenient")]`.
+/// Works for any [`LenientParse`] type. Use with `#[serde(deserialize_with = "lenient")]`.
 ///
 /// # Examples
 ///
@@ -24,8 +27,7 @@ use serde::{Deserialize, Deserializer};
 pub fn lenient<'de, D, T>(deserializer: D) -> Result<T, D::Error>
 where
     D: Deserializer<'de>,
-    T: std::str::FromStr + Deserialize<'de>,
-    T::Err: std::fmt::Display,
+    T: LenientParse,
 {
     LenientValue::deserialize(deserializer)?
         .parse()
@@ -57,11 +59,257 @@ where
 pub fn lenient_option<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
 where
     D: Deserializer<'de>,
-    T: std::str::FromStr + Deserialize<'de>,
-    T::Err: std::fmt::Display,
+    T: LenientParse,
 {
     Option::<LenientValue>::deserialize(deserializer)?
         .map(|v| v.parse())
         .transpose()
         .map_err(serde::de::Error::custom)
 }
+
+/// Deserialize a `String` from a string or any scalar value.
+///
+/// Numbers and booleans are rendered canonically: `12345` becomes `"12345"`, `1.50`
+/// becomes `"1.5"` and `true` becomes `"true"`.
+///
+/// # Examples
+///
+/// ```
+/// use serde::Deserialize;
+/// use serde_tuplex::lenient_string;
+///
+/// #[derive(Deserialize)]
+/// struct Account {
+///     #[serde(deserialize_with = "lenient_string")]
+///     id: String,
+/// }
+///
+/// let a: Account = serde_json::from_str(r#"{"id": 12345}"#).unwrap();
+/// assert_eq!(a.id, "12345");
+/// let a: Account = serde_json::from_str(r#"{"id": "12345"}"#).unwrap();
+/// assert_eq!(a.id, "12345");
+/// ```
+pub fn lenient_string<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    lenient(deserializer)
+}
+
+/// Deserialize a `bool` from `true`, `1`, `"yes"`, `"on"` and friends.
+///
+/// Strings are matched case-insensitively against `true`/`t`/`yes`/`y`/`on`/`1` and
+/// `false`/`f`/`no`/`n`/`off`/`0`. Native numbers must be `1` or `0`.
+///
+/// # Examples
+///
+/// ```
+/// use serde::Deserialize;
+/// use serde_tuplex::lenient_bool;
+///
+/// #[derive(Deserialize)]
+/// struct Flags {
+///     #[serde(deserialize_with = "lenient_bool")]
+///     enabled: bool,
+/// }
+///
+/// let f: Flags = serde_json::from_str(r#"{"enabled": "Yes"}"#).unwrap();
+/// assert!(f.enabled);
+/// let f: Flags = serde_json::from_str(r#"{"enabled": 0}"#).unwrap();
+/// assert!(!f.enabled);
+/// ```
+pub fn lenient_bool<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    lenient(deserializer)
+}
+
+/// Deserialize `Option<bool>` with the [`lenient_bool`] vocabulary.
+///
+/// **Important**: Must be combined with `#[serde(default)]` to handle missing fields.
+pub fn lenient_bool_option<'de, D>(deserializer: D) -> Result<Option<bool>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    lenient_option(deserializer)
+}
+
+/// Deserialize an integer from a number or a `0x`/`0o`/`0b` prefixed string.
+///
+/// Signs and `_` between digits are accepted: `"0x1f"`, `"-0b1010"`, `"0xdead_beef"`.
+///
+/// # Examples
+///
+/// ```
+/// use serde::Deserialize;
+/// use serde_tuplex::lenient_radix;
+///
+/// #[derive(Deserialize)]
+/// struct Register {
+///     #[serde(deserialize_with = "lenient_radix")]
+///     address: u32,
+/// }
+///
+/// let r: Register = serde_json::from_str(r#"{"address": "0x1f"}"#).unwrap();
+/// assert_eq!(r.address, 31);
+/// let r: Register = serde_json::from_str(r#"{"address": 31}"#).unwrap();
+/// assert_eq!(r.address, 31);
+/// ```
+pub fn lenient_radix<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: RadixParse,
+{
+    LenientValue::deserialize(deserializer)?
+        .parse_radix()
+        .map_err(serde::de::Error::custom)
+}
+
+/// Deserialize `Option<T>` with [`lenient_radix`] parsing.
+///
+/// **Important**: Must be combined with `#[serde(default)]` to handle missing fields.
+pub fn lenient_radix_option<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: RadixParse,
+{
+    Option::<LenientValue>::deserialize(deserializer)?
+        .map(|v| v.parse_radix())
+        .transpose()
+        .map_err(serde::de::Error::custom)
+}
+
+/// Deserialize a [`Duration`] from seconds or a duration string.
+///
+/// Accepts `90`, `"90"`, `1.5`, suffixed strings such as `"1m30s"`, `"500ms"` or `"1h 30m"`,
+/// and ISO-8601 durations such as `"PT1M30S"`. Bare numbers are seconds; use
+/// `#[serde_tuplex(duration = "ms")]` with the derives for another unit.
+///
+/// # Examples
+///
+/// ```
+/// use serde::Deserialize;
+/// use serde_tuplex::lenient_duration;
+/// use std::time::Duration;
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     #[serde(deserialize_with = "lenient_duration")]
+///     timeout: Duration,
+/// }
+///
+/// let c: Config = serde_json::from_str(r#"{"timeout": "1m30s"}"#).unwrap();
+/// assert_eq!(c.timeout, Duration::from_secs(90));
+/// let c: Config = serde_json::from_str(r#"{"timeout": "PT1.5S"}"#).unwrap();
+/// assert_eq!(c.timeout, Duration::from_millis(1500));
+/// ```
+pub fn lenient_duration<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    lenient(deserializer)
+}
+
+/// Deserialize `Option<Duration>` with [`lenient_duration`] parsing.
+///
+/// **Important**: Must be combined with `#[serde(default)]` to handle missing fields.
+pub fn lenient_duration_option<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    lenient_option(deserializer)
+}
+
+/// Deserialize a byte count from a number or a string such as `"1MB"`, `"1 MiB"` or `"512k"`.
+///
+/// Works for any [`LenientParse`] integer. Suffixes ignore case; `k`/`kB` are SI (1000) and
+/// `Ki`/`KiB` are IEC (1024), up to exa. Fractional bytes and overflow are errors.
+///
+/// # Examples
+///
+/// ```
+/// use serde::Deserialize;
+/// use serde_tuplex::lenient_bytes;
+///
+/// #[derive(Deserialize)]
+/// struct Limits {
+///     #[serde(deserialize_with = "lenient_bytes")]
+///     memory: u64,
+/// }
+///
+/// let l: Limits = serde_json::from_str(r#"{"memory": "1 MiB"}"#).unwrap();
+/// assert_eq!(l.memory, 1_048_576);
+/// let l: Limits = serde_json::from_str(r#"{"memory": "512k"}"#).unwrap();
+/// assert_eq!(l.memory, 512_000);
+/// ```
+pub fn lenient_bytes<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: LenientParse,
+{
+    LenientValue::deserialize(deserializer)?
+        .parse_bytes()
+        .map_err(serde::de::Error::custom)
+}
+
+/// Deserialize `Option<T>` with [`lenient_bytes`] parsing.
+///
+/// **Important**: Must be combined with `#[serde(default)]` to handle missing fields.
+pub fn lenient_bytes_option<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: LenientParse,
+{
+    Option::<LenientValue>::deserialize(deserializer)?
+        .map(|v| v.parse_bytes())
+        .transpose()
+        .map_err(serde::de::Error::custom)
+}
+
+/// Deserialize a ratio from a number or a percentage string such as `"12.5%"`.
+///
+/// A `%` suffix divides by 100; bare numbers are already ratios, so `0.125` and `"12.5%"`
+/// give the same value. Use [`LenientValue::parse_percent`] with [`PercentMode::Percent`] when
+/// bare numbers are percentages too.
+///
+/// # Examples
+///
+/// ```
+/// use serde::Deserialize;
+/// use serde_tuplex::lenient_percent;
+///
+/// #[derive(Deserialize)]
+/// struct Stats {
+///     #[serde(deserialize_with = "lenient_percent")]
+///     hit_rate: f64,
+/// }
+///
+/// let s: Stats = serde_json::from_str(r#"{"hit_rate": "12.5%"}"#).unwrap();
+/// assert_eq!(s.hit_rate, 0.125);
+/// let s: Stats = serde_json::from_str(r#"{"hit_rate": 0.125}"#).unwrap();
+/// assert_eq!(s.hit_rate, 0.125);
+/// ```
+pub fn lenient_percent<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: LenientParse,
+{
+    LenientValue::deserialize(deserializer)?
+        .parse_percent(PercentMode::Ratio)
+        .map_err(serde::de::Error::custom)
+}
+
+/// Deserialize `Option<T>` with [`lenient_percent`] parsing.
+///
+/// **Important**: Must be combined with `#[serde(default)]` to handle missing fields.
+pub fn lenient_percent_option<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: LenientParse,
+{
+    Option::<LenientValue>::deserialize(deserializer)?
+        .map(|v| v.parse_percent(PercentMode::Ratio))
+        .transpose()
+        .map_err(serde::de::Error::custom)
+}
@@ -0,0 +1,55 @@
+//! Arbitrary-precision decimal types behind the `rust_decimal` and `bigdecimal` features.
+//!
+//! Native numbers reach [`LenientParse`] as their exact text under `arbitrary_precision`, and
+//! floats otherwise go through their shortest round-trip form, so `12.345` stays `12.345`.
+
+use crate::normalize::Normalize;
+use crate::parse::LenientParse;
+
+#[cfg(feature = "rust_decimal")]
+impl LenientParse for rust_decimal::Decimal {
+    const NORMALIZE: Normalize = Normalize::DEFAULT;
+
+    fn parse_f64(v: f64) -> Result<Self, String> {
+        if !v.is_finite() {
+            return Err(format!("{} is not a valid decimal", v));
+        }
+        // `Display` for floats is the shortest text that reads back as `v`.
+        Self::parse_str(&v.to_string())
+    }
+
+    fn parse_bool(v: bool) -> Result<Self, String> {
+        Err(format!("{} is not a valid decimal", v))
+    }
+
+    fn parse_str(s: &str) -> Result<Self, String> {
+        let parsed = if s.contains(['e', 'E']) {
+            rust_decimal::Decimal::from_scientific(s)
+        } else {
+            s.parse()
+        };
+        parsed.map_err(|e| format!("invalid decimal {:?}: {}", s, e))
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl LenientParse for bigdecimal::BigDecimal {
+    const NORMALIZE: Normalize = Normalize::DEFAULT;
+
+    fn parse_f64(v: f64) -> Result<Self, String> {
+        if !v.is_finite() {
+            return Err(format!("{} is not a valid decimal", v));
+        }
+        // `Display` for floats is the shortest text that reads back as `v`.
+        Self::parse_str(&v.to_string())
+    }
+
+    fn parse_bool(v: bool) -> Result<Self, String> {
+        Err(format!("{} is not a valid decimal", v))
+    }
+
+    fn parse_str(s: &str) -> Result<Self, String> {
+        s.parse()
+            .map_err(|e| format!("invalid decimal {:?}: {}", s, e))
+    }
+}
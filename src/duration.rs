@@ -0,0 +1,277 @@
+//! Durations from numbers, suffixed strings and ISO-8601.
+
+use crate::normalize::Normalize;
+use crate::number::DecimalParts;
+use crate::parse::LenientParse;
+use std::fmt::Write;
+use std::time::Duration;
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// Digits of a fraction that can still affect a nanosecond count.
+const MAX_FRACTION_DIGITS: usize = 24;
+
+/// The unit of a bare number read as a [`Duration`].
+///
+/// Selected with `#[serde_tuplex(duration = "ms")]`; the names are `"ns"`, `"us"`, `"ms"`,
+/// `"s"`, `"m"`, `"h"` and `"d"`, the same suffixes accepted in strings such as `"1m30s"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DurationUnit {
+    Nanos,
+    Micros,
+    Millis,
+    Secs,
+    Mins,
+    Hours,
+    Days,
+}
+
+impl DurationUnit {
+    /// The unit for a suffix such as `"ms"` or `"min"`, ignoring case.
+    pub fn from_suffix(suffix: &str) -> Option<Self> {
+        Some(match suffix.to_ascii_lowercase().as_str() {
+            "ns" => DurationUnit::Nanos,
+            "us" | "µs" => DurationUnit::Micros,
+            "ms" => DurationUnit::Millis,
+            "s" | "sec" | "secs" => DurationUnit::Secs,
+            "m" | "min" | "mins" => DurationUnit::Mins,
+            "h" | "hr" | "hrs" => DurationUnit::Hours,
+            "d" | "day" | "days" => DurationUnit::Days,
+            _ => return None,
+        })
+    }
+
+    /// Nanoseconds in one unit.
+    pub const fn nanos(self) -> u128 {
+        match self {
+            DurationUnit::Nanos => 1,
+            DurationUnit::Micros => 1_000,
+            DurationUnit::Millis => 1_000_000,
+            DurationUnit::Secs => NANOS_PER_SEC,
+            DurationUnit::Mins => 60 * NANOS_PER_SEC,
+            DurationUnit::Hours => 3_600 * NANOS_PER_SEC,
+            DurationUnit::Days => 86_400 * NANOS_PER_SEC,
+        }
+    }
+}
+
+/// Parse `3000` (in `unit`), `"3s"`, `"1m30s"`, `"1.5h"` or `"PT1M30S"`.
+pub(crate) fn parse_duration_str(s: &str, unit: DurationUnit) -> Result<Duration, String> {
+    let invalid = |reason: &str| format!("invalid duration {:?}: {}", s, reason);
+    let trimmed = s.trim();
+    let nanos = if let Some(parts) = DecimalParts::parse(trimmed) {
+        if parts.negative && parts.digits.bytes().any(|b| b != b'0') {
+            return Err(invalid("durations cannot be negative"));
+        }
+        let plain = parts.to_plain().ok_or_else(|| invalid("too large"))?;
+        let plain = plain.trim_start_matches('-');
+        let (int, frac) = plain.split_once('.').unwrap_or((plain, ""));
+        scaled_nanos(int, frac, unit)
+    } else if let Some(iso) = trimmed.strip_prefix(['P', 'p']) {
+        parse_iso8601(iso).map_err(|reason| invalid(&reason))?
+    } else {
+        parse_suffixed(trimmed).map_err(|reason| invalid(&reason))?
+    };
+    nanos
+        .and_then(duration_from_nanos)
+        .ok_or_else(|| invalid("too large"))
+}
+
+/// Parse a native number given in `unit`.
+pub(crate) fn duration_from_number(v: f64, unit: DurationUnit) -> Result<Duration, String> {
+    if !v.is_finite() || v < 0.0 {
+        return Err(format!("{} is not a valid duration", v));
+    }
+    // Exponent form keeps the text short, so errors for `1e300` stay readable.
+    parse_duration_str(&format!("{:e}", v), unit)
+}
+
+/// Parse `[-]digits` given in `unit`.
+pub(crate) fn duration_from_int(v: i128, unit: DurationUnit) -> Result<Duration, String> {
+    u128::try_from(v)
+        .ok()
+        .and_then(|v| v.checked_mul(unit.nanos()))
+        .and_then(duration_from_nanos)
+        .ok_or_else(|| format!("{} is not a valid duration", v))
+}
+
+/// `int.frac` units in nanoseconds, truncating below one nanosecond.
+pub(crate) fn scaled_nanos(int: &str, frac: &str, unit: DurationUnit) -> Option<u128> {
+    let whole = if int.is_empty() {
+        0
+    } else {
+        int.parse::<u128>().ok()?.checked_mul(unit.nanos())?
+    };
+    let frac = &frac[..frac.len().min(MAX_FRACTION_DIGITS)];
+    if frac.is_empty() {
+        return Some(whole);
+    }
+    let scale = 10u128.pow(frac.len() as u32);
+    let part = frac.parse::<u128>().ok()? * unit.nanos() / scale;
+    whole.checked_add(part)
+}
+
+fn duration_from_nanos(nanos: u128) -> Option<Duration> {
+    let secs = u64::try_from(nanos / NANOS_PER_SEC).ok()?;
+    Some(Duration::new(secs, (nanos % NANOS_PER_SEC) as u32))
+}
+
+/// Split `1.5h` style components: a number followed by a designator.
+fn components(s: &str) -> Result<Vec<(&str, &str, &str)>, String> {
+    let mut out = Vec::new();
+    let mut rest = s.trim_start();
+    while !rest.is_empty() {
+        let number_len = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))
+            .unwrap_or(rest.len());
+        let (number, tail) = rest.split_at(number_len);
+        let tail = tail.trim_start();
+        let unit_len = tail
+            .find(|c: char| c.is_ascii_digit() || c.is_whitespace())
+            .unwrap_or(tail.len());
+        let (unit, tail) = tail.split_at(unit_len);
+        let (int, frac) = number.split_once(['.', ',']).unwrap_or((number, ""));
+        let valid = |d: &str| d.bytes().all(|b| b.is_ascii_digit());
+        if int.is_empty() && frac.is_empty() || !valid(int) || !valid(frac) {
+            return Err("expected a number".to_string());
+        }
+        if unit.is_empty() {
+            return Err("expected a unit after each number".to_string());
+        }
+        out.push((int, frac, unit));
+        rest = tail.trim_start();
+    }
+    if out.is_empty() {
+        return Err("empty duration".to_string());
+    }
+    Ok(out)
+}
+
+/// `1m30s`, `1h 30m`, `500ms`.
+fn parse_suffixed(s: &str) -> Result<Option<u128>, String> {
+    let mut total = Some(0u128);
+    for (int, frac, suffix) in components(s)? {
+        let unit = DurationUnit::from_suffix(suffix)
+            .ok_or_else(|| format!("unknown unit {:?}", suffix))?;
+        total = total
+            .zip(scaled_nanos(int, frac, unit))
+            .and_then(|(a, b)| a.checked_add(b));
+    }
+    Ok(total)
+}
+
+/// The part of `PnDTnHnMnS` after the `P`.
+fn parse_iso8601(s: &str) -> Result<Option<u128>, String> {
+    let (date, time) = match s.split_once(['T', 't']) {
+        Some((_, "")) => return Err("missing time after `T`".to_string()),
+        Some((date, time)) => (date, time),
+        None => (s, ""),
+    };
+    let mut total = Some(0u128);
+    for (part, is_time) in [(date, false), (time, true)] {
+        if part.is_empty() {
+            continue;
+        }
+        for (int, frac, designator) in components(part)? {
+            let unit = match (designator.to_ascii_uppercase().as_str(), is_time) {
+                ("W", false) => {
+                    let days = scaled_nanos(int, frac, DurationUnit::Days);
+                    total = total
+                        .zip(days)
+                        .and_then(|(a, b)| a.checked_add(b.checked_mul(7)?));
+                    continue;
+                }
+                ("D", false) => DurationUnit::Days,
+                ("Y" | "M", false) => {
+                    return Err("years and months have no fixed length".to_string());
+                }
+                ("H", true) => DurationUnit::Hours,
+                ("M", true) => DurationUnit::Mins,
+                ("S", true) => DurationUnit::Secs,
+                _ => return Err(format!("unexpected designator {:?}", designator)),
+            };
+            total = total
+                .zip(scaled_nanos(int, frac, unit))
+                .and_then(|(a, b)| a.checked_add(b));
+        }
+    }
+    if date.is_empty() && time.is_empty() {
+        return Err("empty duration".to_string());
+    }
+    Ok(total)
+}
+
+/// `90.5s` as `"1m30s500ms"`; zero is `"0s"`.
+pub(crate) fn format_human(d: Duration) -> String {
+    let mut nanos = d.as_nanos();
+    if nanos == 0 {
+        return "0s".to_string();
+    }
+    let mut out = String::new();
+    for (unit, suffix) in [
+        (DurationUnit::Days, "d"),
+        (DurationUnit::Hours, "h"),
+        (DurationUnit::Mins, "m"),
+        (DurationUnit::Secs, "s"),
+        (DurationUnit::Millis, "ms"),
+        (DurationUnit::Micros, "us"),
+        (DurationUnit::Nanos, "ns"),
+    ] {
+        let count = nanos / unit.nanos();
+        if count > 0 {
+            let _ = write!(out, "{}{}", count, suffix);
+            nanos %= unit.nanos();
+        }
+    }
+    out
+}
+
+/// `90.5s` as `"PT1M30.5S"`; zero is `"PT0S"`. Days are written as hours.
+pub(crate) fn format_iso8601(d: Duration) -> String {
+    let secs = d.as_secs();
+    let (hours, mins, secs) = (secs / 3600, secs / 60 % 60, secs % 60);
+    let mut out = String::from("PT");
+    if hours > 0 {
+        let _ = write!(out, "{}H", hours);
+    }
+    if mins > 0 {
+        let _ = write!(out, "{}M", mins);
+    }
+    let nanos = d.subsec_nanos();
+    if nanos > 0 {
+        let frac = format!("{:09}", nanos);
+        let _ = write!(out, "{}.{}S", secs, frac.trim_end_matches('0'));
+    } else if secs > 0 || out.len() == 2 {
+        let _ = write!(out, "{}S", secs);
+    }
+    out
+}
+
+/// Bare numbers are seconds; strings may also be `"1m30s"` or `"PT1M30S"`.
+impl LenientParse for Duration {
+    const NORMALIZE: Normalize = Normalize::DEFAULT;
+
+    fn parse_i64(v: i64) -> Result<Self, String> {
+        duration_from_int(i128::from(v), DurationUnit::Secs)
+    }
+
+    fn parse_u64(v: u64) -> Result<Self, String> {
+        duration_from_int(i128::from(v), DurationUnit::Secs)
+    }
+
+    fn parse_i128(v: i128) -> Result<Self, String> {
+        duration_from_int(v, DurationUnit::Secs)
+    }
+
+    fn parse_f64(v: f64) -> Result<Self, String> {
+        duration_from_number(v, DurationUnit::Secs)
+    }
+
+    fn parse_bool(v: bool) -> Result<Self, String> {
+        Err(format!("{} is not a valid duration", v))
+    }
+
+    fn parse_str(s: &str) -> Result<Self, String> {
+        parse_duration_str(s, DurationUnit::Secs)
+    }
+}
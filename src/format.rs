@@ -0,0 +1,119 @@
+//! Locale-specific number strings.
+
+use std::borrow::Cow;
+
+/// Currency symbols removed from the start or end of a formatted number.
+const CURRENCY_SYMBOLS: &[char] = &[
+    '$', '€', '£', '¥', '₹', '₩', '₽', '¢', '₺', '₴', '₦', '₱', '₫', '₪', '฿', '₿',
+];
+
+/// How a producer writes numbers: grouping separators and decimal mark.
+///
+/// [`normalize`](Self::normalize) rewrites such a string into the plain `1234.56` form the
+/// parsers expect. Currency symbols at either end are dropped, grouping separators are
+/// removed after checking they sit between groups of three digits, and the decimal mark
+/// becomes `.`. Selected with `#[serde_tuplex(number_format = "de")]`.
+///
+/// # Examples
+///
+/// ```
+/// use serde_tuplex::NumberFormat;
+///
+/// assert_eq!(NumberFormat::DE.normalize("1.234,56").unwrap(), "1234.56");
+/// assert_eq!(NumberFormat::EN.normalize("$1,234").unwrap(), "1234");
+/// assert_eq!(NumberFormat::FR.normalize("-1 234,5 €").unwrap(), "-1234.5");
+/// assert!(NumberFormat::EN.normalize("1,5").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberFormat {
+    grouping: &'static [char],
+    decimal: char,
+}
+
+impl NumberFormat {
+    /// `1,234.56`
+    pub const EN: NumberFormat = NumberFormat::new(&[','], '.');
+    /// `1.234,56`
+    pub const DE: NumberFormat = NumberFormat::new(&['.'], ',');
+    /// `1 234,56`, with a space, no-break space or narrow no-break space.
+    pub const FR: NumberFormat = NumberFormat::new(&[' ', '\u{a0}', '\u{202f}'], ',');
+    /// `1'234.56`
+    pub const CH: NumberFormat = NumberFormat::new(&['\'', '’'], '.');
+
+    /// A custom format; any of `grouping` separates thousands.
+    pub const fn new(grouping: &'static [char], decimal: char) -> Self {
+        NumberFormat { grouping, decimal }
+    }
+
+    /// The predefined format for `"en"`, `"de"`, `"fr"` or `"ch"`.
+    pub fn profile(name: &str) -> Option<Self> {
+        match name {
+            "en" => Some(Self::EN),
+            "de" => Some(Self::DE),
+            "fr" => Some(Self::FR),
+            "ch" => Some(Self::CH),
+            _ => None,
+        }
+    }
+
+    /// Rewrite `s` into plain `[-]1234.56` form. Borrows when nothing changes.
+    pub fn normalize<'a>(&self, s: &'a str) -> Result<Cow<'a, str>, String> {
+        let invalid = |reason: &str| format!("invalid number {:?}: {}", s, reason);
+
+        // The sign may sit on either side of the currency symbol: `-$5` or `$-5`.
+        let (sign, rest) = split_sign(s.trim());
+        let rest = strip_currency(rest);
+        let (sign, body) = if sign.is_empty() {
+            split_sign(rest)
+        } else {
+            (sign, rest)
+        };
+
+        let (int, frac) = match body.split_once(self.decimal) {
+            Some((int, frac)) => (int, Some(frac)),
+            None => (body, None),
+        };
+        if let Some(frac) = frac {
+            if frac.contains(self.decimal) {
+                return Err(invalid("more than one decimal mark"));
+            }
+            if frac.contains(self.grouping) {
+                return Err(invalid("grouping separator after the decimal mark"));
+            }
+        }
+
+        let groups: Vec<&str> = int.split(self.grouping).collect();
+        if groups.len() > 1 {
+            let valid = (1..=3).contains(&groups[0].len())
+                && groups[1..].iter().all(|g| g.len() == 3)
+                && groups.iter().all(|g| g.bytes().all(|b| b.is_ascii_digit()));
+            if !valid {
+                return Err(invalid("misplaced grouping separator"));
+            }
+        }
+
+        if groups.len() == 1 && self.decimal == '.' && sign.len() + body.len() == s.len() {
+            return Ok(Cow::Borrowed(s));
+        }
+        let mut out = String::with_capacity(s.len());
+        out.push_str(sign);
+        groups.iter().for_each(|g| out.push_str(g));
+        if let Some(frac) = frac {
+            out.push('.');
+            out.push_str(frac);
+        }
+        Ok(Cow::Owned(out))
+    }
+}
+
+fn split_sign(s: &str) -> (&str, &str) {
+    match s.as_bytes().first() {
+        Some(b'-') | Some(b'+') => s.split_at(1),
+        _ => ("", s),
+    }
+}
+
+fn strip_currency(s: &str) -> &str {
+    let s = s.strip_prefix(CURRENCY_SYMBOLS).map_or(s, str::trim_start);
+    s.strip_suffix(CURRENCY_SYMBOLS).map_or(s, str::trim_end)
+}
@@ -1,98 +1,554 @@
-//! Internal types for lenient parsing. Not part of public API.
+//! Support code for the derive macros. Not part of the public API.
 
-use serde::{Deserialize, Deserializer};
+pub use crate::bytes::ByteUnits;
+use crate::duration::DurationUnit;
+use crate::parse::{FALSE_STRINGS, RadixParse, TRUE_STRINGS, integral_text, parse_bool_str};
+use crate::percent::to_percent_text;
+use crate::timestamp::{Timestamp, TimestampUnit};
+use crate::value::LenientValue;
 use std::fmt;
-use std::str::FromStr;
-
-/// Captures numeric values in either string or native format.
-#[derive(Debug)]
-pub enum LenientValue {
-    String(String),
-    I64(i64),
-    U64(u64),
-    F64(f64),
-}
-
-impl LenientValue {
-    /// Parse to target type via `FromStr`.
-    pub fn parse<T>(&self) -> Result<T, String>
-    where
-        T: FromStr,
-        T::Err: fmt::Display,
-    {
-        match self {
-            LenientValue::String(s) => s.parse().map_err(|e| format!("{}", e)),
-            LenientValue::I64(v) => v.to_string().parse().map_err(|e| format!("{}", e)),
-            LenientValue::U64(v) => v.to_string().parse().map_err(|e| format!("{}", e)),
-            LenientValue::F64(v) => v.to_string().parse().map_err(|e| format!("{}", e)),
-        }
+use std::ops::{Bound, RangeBounds};
+use std::time::Duration;
+
+#[doc(hidden)]
+impl LenientValue<'_> {
+    /// Parse to target type, clamping out-of-range values to its min/max.
+    pub fn parse_saturating<T: BoundedParse>(&self) -> Result<T, String> {
+        T::parse_saturating(self)
+    }
+
+    /// Parse to target type, wrapping out-of-range integers (two's complement).
+    pub fn parse_wrapping<T: BoundedParse>(&self) -> Result<T, String> {
+        T::parse_wrapping(self)
     }
 }
 
-impl<'de> Deserialize<'de> for LenientValue {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        struct LenientValueVisitor;
+/// Overflow-aware parsing for primitive numerics.
+pub trait BoundedParse: Sized {
+    fn parse_saturating(value: &LenientValue<'_>) -> Result<Self, String>;
+    fn parse_wrapping(value: &LenientValue<'_>) -> Result<Self, String>;
+}
 
-        impl<'de> serde::de::Visitor<'de> for LenientValueVisitor {
-            type Value = LenientValue;
+/// Parse `[+-]digits` modulo 2^128, ignoring overflow.
+fn wrapping_digits(s: &str) -> Option<u128> {
+    let (negative, digits) = match s.as_bytes().first()? {
+        b'-' => (true, &s[1..]),
+        b'+' => (false, &s[1..]),
+        _ => (false, s),
+    };
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let magnitude = digits.bytes().fold(0u128, |acc, b| {
+        acc.wrapping_mul(10).wrapping_add(u128::from(b - b'0'))
+    });
+    Some(if negative {
+        magnitude.wrapping_neg()
+    } else {
+        magnitude
+    })
+}
 
-            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                formatter.write_str("a number or string")
+/// Sign of a `[+-]digits` string too large for `i128`.
+fn overflowing_sign(s: &str) -> Option<bool> {
+    wrapping_digits(s).map(|_| s.starts_with('-'))
+}
+
+/// Check that a float denotes a whole number.
+fn integral_float(v: f64) -> Result<f64, String> {
+    if v.is_nan() {
+        return Err("NaN is not a valid integer".to_string());
+    }
+    if v.is_finite() && v.fract() != 0.0 {
+        return Err(format!("{} is not an integer", v));
+    }
+    Ok(v)
+}
+
+macro_rules! impl_bounded_int {
+    ($($ty:ty),*) => {$(
+        impl BoundedParse for $ty {
+            fn parse_saturating(value: &LenientValue<'_>) -> Result<Self, String> {
+                let clamp = |v: i128| <$ty>::try_from(v).unwrap_or(if v < 0 { <$ty>::MIN } else { <$ty>::MAX });
+                // Fractions are only an error within range; `as` saturates outside it.
+                let from_float = |v: f64| {
+                    if v.fract() != 0.0 && v > <$ty>::MIN as f64 && v < <$ty>::MAX as f64 {
+                        Err(format!("{} is not an integer", v))
+                    } else if v.is_nan() {
+                        Err("NaN is not a valid integer".to_string())
+                    } else {
+                        Ok(v as $ty)
+                    }
+                };
+                match value {
+                    LenientValue::I64(v) => Ok(clamp(i128::from(*v))),
+                    LenientValue::U64(v) => Ok(clamp(i128::from(*v))),
+                    LenientValue::I128(v) => Ok(clamp(*v)),
+                    LenientValue::U128(v) => Ok(<$ty>::try_from(*v).unwrap_or(<$ty>::MAX)),
+                    LenientValue::F64(v) => from_float(*v),
+                    LenientValue::Bool(_) => value.parse(),
+                    LenientValue::String(s) | LenientValue::Number(s) => match s.parse::<$ty>() {
+                        Ok(v) => Ok(v),
+                        Err(err) => {
+                            if let Ok(v) = s.parse::<i128>() {
+                                Ok(clamp(v))
+                            } else if let Some(negative) = overflowing_sign(s) {
+                                Ok(if negative { <$ty>::MIN } else { <$ty>::MAX })
+                            } else if let Ok(v) = s.parse::<f64>() {
+                                from_float(v)
+                            } else {
+                                Err(err.to_string())
+                            }
+                        }
+                    },
+                }
             }
 
-            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                Ok(LenientValue::String(value.to_string()))
+            fn parse_wrapping(value: &LenientValue<'_>) -> Result<Self, String> {
+                match value {
+                    LenientValue::I64(v) => Ok(*v as $ty),
+                    LenientValue::U64(v) => Ok(*v as $ty),
+                    LenientValue::I128(v) => Ok(*v as $ty),
+                    LenientValue::U128(v) => Ok(*v as $ty),
+                    LenientValue::F64(v) => {
+                        // `{:.0}` prints the exact integer, which then wraps like a digit string.
+                        let v = integral_float(*v)?;
+                        wrapping_digits(&format!("{:.0}", v))
+                            .map(|v| v as $ty)
+                            .ok_or_else(|| format!("{} is too large to wrap", v))
+                    }
+                    LenientValue::Bool(_) => value.parse(),
+                    LenientValue::String(s) | LenientValue::Number(s) => match s.parse::<$ty>() {
+                        Ok(v) => Ok(v),
+                        Err(err) if s.contains(['.', 'e', 'E']) => {
+                            let digits = integral_text(s, stringify!($ty))?;
+                            wrapping_digits(&digits)
+                                .map(|v| v as $ty)
+                                .ok_or_else(|| err.to_string())
+                        }
+                        Err(err) => wrapping_digits(s)
+                            .map(|v| v as $ty)
+                            .ok_or_else(|| err.to_string()),
+                    },
+                }
             }
+        }
+    )*};
+}
 
-            fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                Ok(LenientValue::I64(value))
+impl_bounded_int!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+/// Check for an explicit `inf`/`infinity` literal, which is not an overflow.
+fn is_infinity_literal(s: &str) -> bool {
+    let s = s.trim_start_matches(['+', '-']);
+    s.eq_ignore_ascii_case("inf") || s.eq_ignore_ascii_case("infinity")
+}
+
+macro_rules! impl_bounded_float {
+    ($($ty:ty),*) => {$(
+        impl BoundedParse for $ty {
+            fn parse_saturating(value: &LenientValue<'_>) -> Result<Self, String> {
+                let v: $ty = value.parse_exact()?;
+                let explicit = matches!(value, LenientValue::String(s) if is_infinity_literal(s));
+                if v.is_infinite() && !explicit {
+                    Ok(if v < 0.0 { <$ty>::MIN } else { <$ty>::MAX })
+                } else {
+                    Ok(v)
+                }
             }
 
-            fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                Ok(LenientValue::U64(value))
+            fn parse_wrapping(_value: &LenientValue<'_>) -> Result<Self, String> {
+                Err("wrapping is not supported for floats".to_string())
             }
+        }
+    )*};
+}
+
+impl_bounded_float!(f32, f64);
+
+/// Fail on NaN and infinities, for `non_finite = "reject"`.
+pub fn reject_non_finite<T: Into<f64> + Copy + fmt::Display>(v: T) -> Result<T, String> {
+    if v.into().is_finite() {
+        Ok(v)
+    } else {
+        Err(format!("{} is not a finite number", v))
+    }
+}
+
+/// Custom field parser for `#[serde_tuplex(parse_with = "...")]`.
+///
+/// Implemented for functions taking `&LenientValue`, `&str` or `f64` and returning
+/// `Result<T, E>` where `E: Display`. `Input` only selects the matching impl.
+pub trait FieldParser<Input, T> {
+    fn parse_field(&self, value: &LenientValue<'_>) -> Result<T, String>;
+}
+
+/// `FieldParser` input marker for `fn(&LenientValue) -> Result<T, E>`.
+pub enum ValueInput {}
+
+/// `FieldParser` input marker for `fn(&str) -> Result<T, E>`.
+///
+/// Native numbers are passed in their `Display` form.
+pub enum StrInput {}
+
+/// `FieldParser` input marker for `fn(f64) -> Result<T, E>`.
+///
+/// Strings are parsed as `f64` first.
+pub enum FloatInput {}
+
+impl<F, T, E> FieldParser<ValueInput, T> for F
+where
+    F: Fn(&LenientValue<'_>) -> Result<T, E>,
+    E: fmt::Display,
+{
+    fn parse_field(&self, value: &LenientValue<'_>) -> Result<T, String> {
+        self(value).map_err(|e| e.to_string())
+    }
+}
+
+impl<F, T, E> FieldParser<StrInput, T> for F
+where
+    F: Fn(&str) -> Result<T, E>,
+    E: fmt::Display,
+{
+    fn parse_field(&self, value: &LenientValue<'_>) -> Result<T, String> {
+        let result = match value {
+            LenientValue::String(s) | LenientValue::Number(s) => self(s),
+            LenientValue::I64(v) => self(&v.to_string()),
+            LenientValue::U64(v) => self(&v.to_string()),
+            LenientValue::I128(v) => self(&v.to_string()),
+            LenientValue::U128(v) => self(&v.to_string()),
+            LenientValue::F64(v) => self(&v.to_string()),
+            LenientValue::Bool(v) => self(&v.to_string()),
+        };
+        result.map_err(|e| e.to_string())
+    }
+}
+
+impl<F, T, E> FieldParser<FloatInput, T> for F
+where
+    F: Fn(f64) -> Result<T, E>,
+    E: fmt::Display,
+{
+    fn parse_field(&self, value: &LenientValue<'_>) -> Result<T, String> {
+        self(value.parse()?).map_err(|e| e.to_string())
+    }
+}
+
+/// Run a `parse_with` parser; the input kind is inferred from its signature.
+pub fn parse_field_with<Input, T, F>(parser: &F, value: &LenientValue<'_>) -> Result<T, String>
+where
+    F: FieldParser<Input, T>,
+{
+    parser.parse_field(value)
+}
+
+/// Parse a `bool` field with a `true_values`/`false_values` vocabulary.
+///
+/// Empty lists fall back to the default vocabulary. Strings are matched case-insensitively;
+/// native numbers are matched by their text.
+pub fn parse_bool_with(
+    value: &LenientValue<'_>,
+    true_values: &[&str],
+    false_values: &[&str],
+) -> Result<bool, String> {
+    let true_values = if true_values.is_empty() {
+        TRUE_STRINGS
+    } else {
+        true_values
+    };
+    let false_values = if false_values.is_empty() {
+        FALSE_STRINGS
+    } else {
+        false_values
+    };
+    match value {
+        LenientValue::Bool(v) => Ok(*v),
+        LenientValue::String(s) | LenientValue::Number(s) => {
+            parse_bool_str(s, true_values, false_values)
+        }
+        other => parse_bool_str(&other.to_string(), true_values, false_values),
+    }
+}
+
+/// Check a parsed value against `range`/`min`/`max` field attributes.
+pub fn check_range<T, E>(
+    field: &str,
+    value: &T,
+    bounds: (Bound<T>, Bound<T>),
+    expected: &str,
+) -> Result<(), E>
+where
+    T: PartialOrd + fmt::Display,
+    E: serde::de::Error,
+{
+    if bounds.contains(value) {
+        Ok(())
+    } else {
+        Err(E::invalid_value(
+            serde::de::Unexpected::Other(&format!("{} for field `{}`", value, field)),
+            &expected,
+        ))
+    }
+}
+
+/// Variant table entry for `#[derive(LenientEnum)]`.
+pub struct EnumVariant {
+    pub name: &'static str,
+    pub aliases: &'static [&'static str],
+    pub discriminant: i128,
+}
+
+/// Find the variant `value` refers to by name, alias or discriminant; returns its table index.
+///
+/// Names and aliases are tried before numeric strings.
+pub fn match_enum_variant(
+    value: &LenientValue<'_>,
+    enum_name: &str,
+    variants: &[EnumVariant],
+    case_insensitive: bool,
+) -> Result<usize, String> {
+    let discriminant = match value {
+        LenientValue::String(s) => {
+            let matches = |candidate: &str| {
+                if case_insensitive {
+                    candidate.eq_ignore_ascii_case(s)
+                } else {
+                    candidate == s
+                }
+            };
+            let by_name = variants
+                .iter()
+                .position(|v| matches(v.name) || v.aliases.iter().any(|a| matches(a)));
+            if let Some(idx) = by_name {
+                return Ok(idx);
+            }
+            s.parse::<i128>().map_err(|_| {
+                let names: Vec<_> = variants.iter().map(|v| v.name).collect();
+                format!(
+                    "unknown variant {:?} of {}, expected one of {}",
+                    s,
+                    enum_name,
+                    names.join(", ")
+                )
+            })?
+        }
+        LenientValue::Bool(v) => {
+            return Err(format!(
+                "expected a variant name or discriminant of {}, found {}",
+                enum_name, v
+            ));
+        }
+        other => other.parse::<i128>()?,
+    };
+    variants
+        .iter()
+        .position(|v| v.discriminant == discriminant)
+        .ok_or_else(|| format!("{} is not a discriminant of {}", discriminant, enum_name))
+}
+
+/// Serialize an enum discriminant using the narrowest integer type that holds it.
+pub fn serialize_discriminant<S: serde::Serializer>(
+    discriminant: i128,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    if let Ok(v) = i64::try_from(discriminant) {
+        serializer.serialize_i64(v)
+    } else if let Ok(v) = u64::try_from(discriminant) {
+        serializer.serialize_u64(v)
+    } else {
+        serializer.serialize_i128(discriminant)
+    }
+}
 
-            fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                Ok(LenientValue::F64(value))
+/// Serializes a `Tuple` field with `serialize = "hex"`.
+pub struct Hex<'a, T>(pub &'a T);
+
+impl<T: RadixParse> serde::Serialize for Hex<'_, T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::ser::serialize_hex(self.0, serializer)
+    }
+}
+
+/// Serializes an `Option` `Tuple` field with `serialize = "hex"`.
+pub struct HexOption<'a, T>(pub &'a Option<T>);
+
+impl<T: RadixParse> serde::Serialize for HexOption<'_, T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::ser::serialize_hex_option(self.0, serializer)
+    }
+}
+
+/// Serializes a `Tuple` field with `serialize = "non_finite_string"`.
+pub struct NonFiniteString<'a, T>(pub &'a T);
+
+impl<T: serde::Serialize + Into<f64> + Copy> serde::Serialize for NonFiniteString<'_, T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::ser::serialize_non_finite(self.0, serializer)
+    }
+}
+
+/// Serializes an `Option` `Tuple` field with `serialize = "non_finite_string"`.
+pub struct NonFiniteStringOption<'a, T>(pub &'a Option<T>);
+
+impl<T: serde::Serialize + Into<f64> + Copy> serde::Serialize for NonFiniteStringOption<'_, T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::ser::serialize_non_finite_option(self.0, serializer)
+    }
+}
+
+/// Output form of a `Tuple` field with `duration = "..."`.
+#[derive(Clone, Copy)]
+pub enum DurationRepr {
+    /// A number of units, integral when exact.
+    Number(DurationUnit),
+    Human,
+    Iso8601,
+}
+
+/// Serializes a `Duration` `Tuple` field in the form chosen by its attributes.
+pub struct DurationField<'a, T>(pub &'a T, pub DurationRepr);
+
+impl serde::Serialize for DurationField<'_, Duration> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.1 {
+            DurationRepr::Number(unit) => {
+                let nanos = self.0.as_nanos();
+                if !nanos.is_multiple_of(unit.nanos()) {
+                    serializer.serialize_f64(nanos as f64 / unit.nanos() as f64)
+                } else if let Ok(count) = u64::try_from(nanos / unit.nanos()) {
+                    serializer.serialize_u64(count)
+                } else {
+                    serializer.serialize_u128(nanos / unit.nanos())
+                }
             }
+            DurationRepr::Human => crate::ser::serialize_duration_human(self.0, serializer),
+            DurationRepr::Iso8601 => crate::ser::serialize_duration_iso8601(self.0, serializer),
+        }
+    }
+}
+
+impl serde::Serialize for DurationField<'_, Option<Duration>> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            Some(v) => serializer.serialize_some(&DurationField(v, self.1)),
+            None => serializer.serialize_none(),
         }
+    }
+}
+
+/// Serializes a `Tuple` field with `bytes` and `serialize = "human"` or `"si"`.
+pub struct ByteSize<'a, T>(pub &'a T, pub ByteUnits);
 
-        deserializer.deserialize_any(LenientValueVisitor)
+impl<T: Copy + TryInto<u128>> serde::Serialize for ByteSize<'_, T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::ser::serialize_bytes_with(*self.0, self.1, serializer)
     }
 }
 
-/// Wrapper for `Option<LenientValue>`.
-#[derive(Debug)]
-pub struct OptionalLenientValue(Option<LenientValue>);
+/// Serializes an `Option` `Tuple` field with `bytes` and `serialize = "human"` or `"si"`.
+pub struct ByteSizeOption<'a, T>(pub &'a Option<T>, pub ByteUnits);
 
-impl OptionalLenientValue {
-    pub fn into_option(self) -> Option<LenientValue> {
-        self.0
+impl<T: Copy + TryInto<u128>> serde::Serialize for ByteSizeOption<'_, T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            Some(v) => serializer.serialize_some(&ByteSize(v, self.1)),
+            None => serializer.serialize_none(),
+        }
     }
 }
 
-impl<'de> Deserialize<'de> for OptionalLenientValue {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        Ok(OptionalLenientValue(Option::<LenientValue>::deserialize(
-            deserializer,
-        )?))
+/// How a `percent` `Tuple` field is written when it is not a plain ratio.
+#[derive(Clone, Copy)]
+pub enum PercentRepr {
+    /// A number in percent, e.g. `12.5` for `0.125`.
+    Number,
+    /// A string such as `"12.5%"`.
+    String,
+}
+
+/// Serializes a `Tuple` field with `percent = "percent"` or `serialize = "percent"`.
+pub struct Percent<'a, T>(pub &'a T, pub PercentRepr);
+
+impl<T: fmt::Display + serde::Serialize> serde::Serialize for Percent<'_, T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.1 {
+            PercentRepr::Number => match to_percent_text(self.0) {
+                Ok(text) => {
+                    serializer.serialize_f64(text.parse().map_err(serde::ser::Error::custom)?)
+                }
+                // NaN and infinities are the same in percent.
+                Err(_) => self.0.serialize(serializer),
+            },
+            PercentRepr::String => crate::ser::serialize_percent(self.0, serializer),
+        }
+    }
+}
+
+/// Serializes an `Option` `Tuple` field like [`Percent`].
+pub struct PercentOption<'a, T>(pub &'a Option<T>, pub PercentRepr);
+
+impl<T: fmt::Display + serde::Serialize> serde::Serialize for PercentOption<'_, T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            Some(v) => serializer.serialize_some(&Percent(v, self.1)),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+/// Serializes a timestamp `Tuple` field with `serialize = "epoch"`.
+pub struct Epoch<'a, T>(pub &'a T, pub TimestampUnit);
+
+impl<T: Timestamp> serde::Serialize for Epoch<'_, T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::timestamp::serialize_epoch(self.0, self.1, serializer)
+    }
+}
+
+/// Serializes an `Option` timestamp `Tuple` field with `serialize = "epoch"`.
+pub struct EpochOption<'a, T>(pub &'a Option<T>, pub TimestampUnit);
+
+impl<T: Timestamp> serde::Serialize for EpochOption<'_, T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            Some(v) => serializer.serialize_some(&Epoch(v, self.1)),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+/// How a `Tuple` field with `serialize = "string"` or `"number"` is written.
+#[derive(Clone, Copy)]
+pub enum TextRepr {
+    /// The `Display` text as a string.
+    String,
+    /// The `Display` text as a native number.
+    Number,
+}
+
+/// Serializes a `Tuple` field through its `Display` text.
+pub struct AsText<'a, T>(pub &'a T, pub TextRepr);
+
+impl<T: fmt::Display> serde::Serialize for AsText<'_, T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.1 {
+            TextRepr::String => crate::ser::serialize_as_string(self.0, serializer),
+            TextRepr::Number => crate::ser::serialize_as_number(self.0, serializer),
+        }
+    }
+}
+
+/// Serializes an `Option` `Tuple` field like [`AsText`].
+pub struct AsTextOption<'a, T>(pub &'a Option<T>, pub TextRepr);
+
+impl<T: fmt::Display> serde::Serialize for AsTextOption<'_, T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            Some(v) => serializer.serialize_some(&AsText(v, self.1)),
+            None => serializer.serialize_none(),
+        }
     }
 }
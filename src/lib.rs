@@ -3,13 +3,17 @@
 //! # Features
 //!
 //! - [`Tuple`] - Serialize/deserialize as `[1,2]` instead of `{"x":1,"y":2}`
-//! - [`Lenient`] - Accept both `123` and `"123"` for numeric fields
+//! - [`Lenient`] - Accept both `123` and `"123"` for numeric fields, `"yes"` for `bool`
 //! - [`TupleLenient`] - Combine both
+//! - [`LenientEnum`] - Unit enums from `1`, `"1"` or `"Active"`
+//! - [`LenientParse`] - Conversion trait for your own types with `#[serde_tuplex(lenient)]`
+//! - [`LenientValue`] - The "number or string" intake, for hand-written visitors
+//! - [`LenientNumber`] - A number field that keeps the exact text it was sent as
 //!
 //! # Examples
 //!
 //! ```
-//! use serde_tuplex::{Lenient, Tuple, TupleLenient};
+//! use serde_tuplex::{Lenient, LenientEnum, Tuple, TupleLenient};
 //!
 //! #[derive(Lenient)]
 //! struct User {
@@ -22,6 +26,9 @@
 //!
 //! #[derive(TupleLenient)]
 //! struct Data { count: u64 }  // Both features
+//!
+//! #[derive(LenientEnum)]
+//! enum Status { Inactive = 0, Active = 1 }  // Accepts 1, "1" or "Active"
 //! ```
 //!
 //! ## Manual control
@@ -55,14 +62,465 @@
 //!     strict: u32,
 //! }
 //! ```
+//!
+//! # Field attributes
+//!
+//! Fields are configured with `#[serde_tuplex(...)]`. Those marked (struct) also work on the
+//! struct, where they apply to every field they fit. Unknown keys are a compile error, a
+//! breaking change from 0.1, which ignored them.
+//!
+//! - `skip` - keep serde's strict parsing; see [Manual control](#manual-control).
+//! - `lenient`, `parse_with = "path"` - your own types and parsers; see
+//!   [Custom types](#custom-types).
+//! - `range = a..=b`, `min`, `max` - see [Range validation](#range-validation).
+//! - `overflow = "saturate"` (or `"wrap"`) - see [Out-of-range values](#out-of-range-values).
+//! - `radix_prefix` (struct) - see [Radix prefixes](#radix-prefixes).
+//! - `number_format = "de"` (struct) - see [Number formats](#number-formats).
+//! - `normalize(...)`, `normalize = "none"` (struct) - see
+//!   [String normalization](#string-normalization).
+//! - `non_finite = "reject"` (or `"null"`) (struct) - see [NaN and infinity](#nan-and-infinity).
+//! - `true_values`, `false_values` - see [Booleans](#booleans).
+//! - `stringify` - see [String fields](#string-fields).
+//! - `duration = "ms"` - see [Durations](#durations).
+//! - `bytes` - see [Byte sizes](#byte-sizes).
+//! - `percent` - see [Percentages](#percentages).
+//! - `timestamp = "ms"` - see [Timestamps](#timestamps).
+//! - `serialize = "..."` - see [Serialization](#serialization).
+//!
+//! # Numbers
+//!
+//! ## Lenient types
+//!
+//! Primitive numbers, `bool` and their `Option`s are lenient without any attribute. So are the
+//! `std::num` wrappers `NonZeroU32` (or `NonZero<u32>`), `Wrapping<T>` and `Saturating<T>`
+//! around a primitive integer, by any path. Out-of-range input behaves like the type's
+//! arithmetic: `Wrapping` wraps, `Saturating` clamps, and the `NonZero` types are range-checked
+//! like their integer and reject `0`:
+//!
+//! ```
+//! use serde_tuplex::Lenient;
+//! use std::num::{NonZeroU16, Wrapping};
+//!
+//! #[derive(Lenient)]
+//! struct Pool {
+//!     size: NonZeroU16,                   // Accepts 8 or "8", not 0
+//!     hits: Option<Wrapping<u8>>,         // 300 -> 44
+//!     misses: core::num::Saturating<u8>,  // 300 -> 255
+//! }
+//!
+//! let json = r#"{"size": "8", "hits": 300, "misses": "300"}"#;
+//! let pool: Pool = serde_json::from_str(json).unwrap();
+//! assert_eq!(pool.size.get(), 8);
+//! assert_eq!(pool.hits, Some(Wrapping(44)));
+//! assert_eq!(pool.misses.0, 255);
+//! assert!(serde_json::from_str::<Pool>(r#"{"size": 0, "misses": 0}"#).is_err());
+//! ```
+//!
+//! ## Out-of-range values
+//!
+//! `#[serde_tuplex(overflow = "saturate" | "wrap" | "error")]` controls what happens when a
+//! value does not fit the target primitive. The default is `"error"`.
+//!
+//! ```
+//! use serde_tuplex::Lenient;
+//!
+//! #[derive(Lenient)]
+//! struct Reading {
+//!     #[serde_tuplex(overflow = "saturate")]
+//!     percent: u8,    // 300 or "300" -> 255, -1 -> 0
+//!     #[serde_tuplex(overflow = "wrap")]
+//!     counter: u16,   // 65537 -> 1
+//! }
+//! ```
+//!
+//! ## Range validation
+//!
+//! `range`, `min` and `max` are checked after parsing, on `Tuple` fields too:
+//!
+//! ```
+//! use serde_tuplex::Lenient;
+//!
+//! #[derive(Lenient)]
+//! struct Endpoint {
+//!     #[serde_tuplex(range = 1..=65535)]
+//!     port: u32,
+//!     #[serde_tuplex(min = 0.0, max = 1.0)]
+//!     ratio: f64,
+//! }
+//! ```
+//!
+//! ## Radix prefixes
+//!
+//! `#[serde_tuplex(radix_prefix)]` lets integer fields accept `"0x1f"`, `"0o17"` and
+//! `"0b1010"`, with an optional sign and `_` between digits. On the struct it applies to every
+//! integer field. `serialize = "hex"` writes the field back as `"0x1f"` from [`Tuple`] and
+//! [`TupleLenient`]; [`lenient_radix`] and [`serialize_hex`] do the same for plain serde.
+//!
+//! ```
+//! use serde_tuplex::TupleLenient;
+//!
+//! #[derive(TupleLenient)]
+//! struct Word {
+//!     #[serde_tuplex(radix_prefix, serialize = "hex")]
+//!     value: u16,     // Accepts 48879, "48879" or "0xbeef"; writes "0xbeef"
+//!     count: u8,
+//! }
+//! ```
+//!
+//! ## Number formats
+//!
+//! `#[serde_tuplex(number_format = "de")]` reads strings such as `"1.234,56"` or `"€ 12,5"`
+//! before parsing. Profiles are `"en"` (`1,234.56`), `"de"` (`1.234,56`), `"fr"` (`1 234,56`)
+//! and `"ch"` (`1'234.56`); `number_format(grouping = ".", decimal = ",")` defines your own.
+//! Set on the struct, it applies to every numeric field. See [`NumberFormat`].
+//!
+//! ```
+//! use serde_tuplex::Lenient;
+//!
+//! #[derive(Lenient)]
+//! struct Invoice {
+//!     #[serde_tuplex(number_format = "de")]
+//!     total: f64,     // Accepts 1234.56, "1.234,56" or "1.234,56 €"
+//! }
+//! ```
+//!
+//! ## String normalization
+//!
+//! Number strings are cleaned up before parsing: whitespace is trimmed, a leading `+` dropped,
+//! Unicode minus signs and full-width digits mapped to ASCII, and `_` between digits removed,
+//! so `" +1_000 "` and `"−４２"` parse. This default set applies to [`lenient`] and the derives
+//! alike; `bool` only trims, and strings and custom types are left as sent. Pick the steps
+//! with `normalize(trim, plus_sign, unicode_minus, full_width, underscores)` or turn them off
+//! with `normalize = "none"`, on a field or the struct. See [`Normalize`].
+//!
+//! ```
+//! use serde_tuplex::Lenient;
+//!
+//! #[derive(Lenient)]
+//! struct Sensor {
+//!     reading: i32,   // Accepts -5, "-5", " −5 " or "−５"
+//!     #[serde_tuplex(normalize = "none")]
+//!     code: u32,      // Accepts "42" but not " 42 "
+//! }
+//! ```
+//!
+//! ## NaN and infinity
+//!
+//! String input such as `"NaN"` or `"inf"` parses into float fields unless
+//! `#[serde_tuplex(non_finite = "reject")]` refuses it; `non_finite = "null"` turns it into
+//! `None` on `Option` fields. On the struct, `"null"` rejects on fields that are not `Option`.
+//! `serialize = "non_finite_string"` writes NaN and infinities as `"NaN"`, `"Infinity"` and
+//! `"-Infinity"` from [`Tuple`] and [`TupleLenient`]; [`serialize_non_finite`] does the same
+//! for plain serde.
+//!
+//! ```
+//! use serde_tuplex::TupleLenient;
+//!
+//! #[derive(TupleLenient)]
+//! struct Sample {
+//!     #[serde_tuplex(non_finite = "reject")]
+//!     value: f64,     // Accepts 1.5 or "1.5", not "NaN"
+//!     #[serde_tuplex(non_finite = "null", serialize = "non_finite_string")]
+//!     reading: Option<f64>,
+//! }
+//! ```
+//!
+//! ## Decimals
+//!
+//! With the `rust_decimal` or `bigdecimal` feature, `rust_decimal::Decimal` and
+//! `bigdecimal::BigDecimal` fields are lenient numerics like the primitives, including
+//! `number_format` and `normalize`. The derive recognises them by crate path, as other crates
+//! have a `Decimal` too; a field written as a bare `Decimal` needs `#[serde_tuplex(lenient)]`.
+//! They are parsed from text rather than through `f64`: strings and `arbitrary_precision`
+//! numbers keep every digit, and other floats use their shortest round-trip form, so `12.345`
+//! stays `12.345`. [`Tuple`] and [`TupleLenient`] write them as strings by default, and
+//! `serialize = "number"` picks a JSON number instead; [`serialize_as_string`] and
+//! [`serialize_as_number`] do the same with plain serde.
+//!
+//! ```
+//! # #[cfg(feature = "rust_decimal")]
+//! # mod example {
+//! use rust_decimal::Decimal;
+//! use serde_tuplex::TupleLenient;
+//!
+//! #[derive(TupleLenient)]
+//! struct Line {
+//!     price: rust_decimal::Decimal,  // Accepts 12.345 or "12.345", writes "12.345"
+//!     #[serde_tuplex(lenient, serialize = "number")]
+//!     tax: Decimal,                  // Bare name; writes 0.5
+//! }
+//! # }
+//! ```
+//!
+//! ## Big integers
+//!
+//! With the `num-bigint` feature, `num_bigint::BigInt` and `num_bigint::BigUint` fields are
+//! lenient numerics; with `primitive-types`, `primitive_types::U256` fields are too. Other crates
+//! define types with the same names, so the crate path is how the derive recognises them; a
+//! field written as a bare `U256` needs `#[serde_tuplex(lenient)]`. They read native numbers,
+//! decimal strings (also `"1e30"` when the value is integral) and `0x`/`0o`/`0b` strings.
+//! [`Tuple`] and [`TupleLenient`] write them as decimal strings, since most JSON readers cannot
+//! hold them as numbers; `serialize = "hex"` or `"number"` picks another form.
+//!
+//! ```
+//! # #[cfg(feature = "primitive-types")]
+//! # mod example {
+//! use primitive_types::U256;
+//! use serde_tuplex::TupleLenient;
+//!
+//! #[derive(TupleLenient)]
+//! struct Transfer {
+//!     value: primitive_types::U256,  // Accepts 21000, "21000" or "0x5208", writes "21000"
+//!     #[serde_tuplex(lenient)]
+//!     fee: U256,                     // Same, by bare name
+//! }
+//! # }
+//! ```
+//!
+//! ## Preserving the original text
+//!
+//! [`LenientNumber`] accepts a number or a numeric string, remembers which it was and the exact
+//! text, and serializes back the same way:
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use serde_tuplex::LenientNumber;
+//!
+//! #[derive(Deserialize, Serialize)]
+//! struct AuditEntry {
+//!     amount: LenientNumber,
+//! }
+//!
+//! let entry: AuditEntry = serde_json::from_str(r#"{"amount": "1.50"}"#).unwrap();
+//! assert_eq!(entry.amount.to_f64(), Some(1.5));
+//! assert_eq!(serde_json::to_string(&entry).unwrap(), r#"{"amount":"1.50"}"#);
+//! ```
+//!
+//! # Other field types
+//!
+//! ## Booleans
+//!
+//! `bool` and `Option<bool>` fields are lenient too. Besides `true`/`false` they accept `1`/`0`
+//! and, ignoring case, the strings `true`/`t`/`yes`/`y`/`on`/`1` and
+//! `false`/`f`/`no`/`n`/`off`/`0`. `true_values`/`false_values` replace that vocabulary for
+//! one field; [`lenient_bool`] and [`lenient_bool_option`] cover plain serde structs.
+//!
+//! ```
+//! use serde_tuplex::Lenient;
+//!
+//! #[derive(Lenient)]
+//! struct Flags {
+//!     enabled: bool,  // Accepts true, 1, "yes" or "ON"
+//!     #[serde_tuplex(true_values = ["ja"], false_values = ["nein"])]
+//!     localized: bool,
+//! }
+//! ```
+//!
+//! ## String fields
+//!
+//! `#[serde_tuplex(stringify)]` lets a `String`, `Option<String>` or `Box<str>` field accept
+//! numbers and booleans, rendered canonically (`12345` -> `"12345"`, `3.0` -> `"3"`).
+//! [`lenient_string`] does the same for plain serde structs.
+//!
+//! ```
+//! use serde_tuplex::Lenient;
+//!
+//! #[derive(Lenient)]
+//! struct Account {
+//!     #[serde_tuplex(stringify)]
+//!     id: String,     // Accepts 12345 or "12345"
+//! }
+//! ```
+//!
+//! ## Durations
+//!
+//! `#[serde_tuplex(duration = "ms")]` parses a `std::time::Duration` or `Option<Duration>` field,
+//! written as `Duration`, `std::time::Duration` or `core::time::Duration`. Bare numbers count the
+//! given unit (`"ns"`, `"us"`, `"ms"`, `"s"`, `"m"`, `"h"` or `"d"`); strings may also be suffixed
+//! (`"3s"`, `"1m30s"`, `"1h 30m"`) or ISO-8601 (`"PT1.5S"`). [`Tuple`] and [`TupleLenient`] write
+//! the number back in the same unit, or `"1m30s"` with `serialize = "human"` and `"PT1M30S"` with
+//! `serialize = "iso8601"`. [`lenient_duration`] reads bare numbers as seconds.
+//!
+//! ```
+//! use serde_tuplex::Lenient;
+//! use std::time::Duration;
+//!
+//! #[derive(Lenient)]
+//! struct Config {
+//!     #[serde_tuplex(duration = "ms")]
+//!     timeout: Duration,  // Accepts 3000, "3000", "3s" or "PT3S"
+//! }
+//! ```
+//!
+//! ## Byte sizes
+//!
+//! `#[serde_tuplex(bytes)]` lets an integer field accept `"1MB"`, `"1 MiB"` or `"512k"` besides
+//! plain numbers. Suffixes ignore case: `k`, `M`, `G`, `T`, `P` and `E` are SI (powers of 1000),
+//! `Ki`, `Mi`, ... are IEC (powers of 1024), and a trailing `B` is optional. Fractional bytes
+//! and values that do not fit are errors. `serialize = "human"` writes `"1.5KiB"` from [`Tuple`]
+//! and [`TupleLenient`], `serialize = "si"` writes `"1.5kB"`; [`lenient_bytes`] and
+//! [`serialize_bytes_human`] cover plain serde.
+//!
+//! ```
+//! use serde_tuplex::Lenient;
+//!
+//! #[derive(Lenient)]
+//! struct Limits {
+//!     #[serde_tuplex(bytes)]
+//!     memory: u64,    // Accepts 1048576, "1048576", "1MiB" or "1.5 GB"
+//! }
+//! ```
+//!
+//! ## Percentages
+//!
+//! `#[serde_tuplex(percent)]` lets a float field accept `"12.5%"` as well as the ratio `0.125`.
+//! With `percent = "percent"` bare numbers are percentages too, so `12.5` also reads as `0.125`.
+//! The decimal point is moved in the text, keeping `"33.3%"` exactly `0.333`. [`Tuple`] and
+//! [`TupleLenient`] write the field back in its bare form, or as `"12.5%"` with
+//! `serialize = "percent"`; [`lenient_percent`] and [`serialize_percent`] cover plain serde.
+//!
+//! ```
+//! use serde_tuplex::Lenient;
+//!
+//! #[derive(Lenient)]
+//! struct Stats {
+//!     #[serde_tuplex(percent)]
+//!     hit_rate: f64,  // Accepts 0.125, "0.125" or "12.5%"
+//!     #[serde_tuplex(percent = "percent")]
+//!     cpu: f32,       // Accepts 12.5, "12.5" or "12.5%", all as 0.125
+//! }
+//! ```
+//!
+//! ## Timestamps
+//!
+//! With the `chrono` or `time` feature, `chrono::DateTime<Utc>` and `time::OffsetDateTime`
+//! fields (and their `Option`s) are lenient: they accept epoch numbers, numeric strings and
+//! RFC 3339 strings such as `"2024-01-02T03:04:05Z"`. Bare numbers are seconds unless
+//! `#[serde_tuplex(timestamp = "ms")]` (or `"us"`, `"ns"`) says otherwise; `timestamp = "auto"`
+//! picks the unit from the magnitude, for feeds that mix them. `serialize = "epoch"` writes the
+//! field back as an epoch number in its unit from [`Tuple`] and [`TupleLenient`].
+//!
+//! ```
+//! # #[cfg(feature = "chrono")]
+//! # mod example {
+//! use chrono::{DateTime, Utc};
+//! use serde_tuplex::Lenient;
+//!
+//! #[derive(Lenient)]
+//! struct Event {
+//!     created: DateTime<Utc>,     // 1700000000, "1700000000" or "2023-11-14T22:13:20Z"
+//!     #[serde_tuplex(timestamp = "auto")]
+//!     seen: DateTime<Utc>,        // 1700000000 (s) or 1700000000123 (ms)
+//! }
+//! # }
+//! ```
+//!
+//! # Serialization
+//!
+//! [`Tuple`] and [`TupleLenient`] write each field with its own `Serialize` unless
+//! `serialize = "..."` picks another form:
+//!
+//! | Value                 | Fields                 | Output                                              |
+//! |-----------------------|------------------------|-----------------------------------------------------|
+//! | `"hex"`               | integers, big integers | `"0x1f"`                                            |
+//! | `"non_finite_string"` | floats                 | `"NaN"`, `"Infinity"`, `"-Infinity"`                |
+//! | `"human"`             | `duration`, `bytes`    | `"1m30s"`, `"1.5KiB"`                               |
+//! | `"iso8601"`           | `duration`             | `"PT1M30S"`                                         |
+//! | `"si"`                | `bytes`                | `"1.5kB"`                                           |
+//! | `"percent"`           | `percent`              | `"12.5%"`                                           |
+//! | `"epoch"`             | timestamps             | epoch number in the `timestamp` unit                |
+//! | `"string"`            | numbers                | `"12.5"`; the default for decimals and big integers |
+//! | `"number"`            | numbers                | `12.5`                                              |
+//!
+//! `duration` fields default to a number in their unit, and `percent` fields to the form they
+//! are read in.
+//!
+//! # Custom types
+//!
+//! Types that implement [`LenientParse`] get the same "number or string" intake when the
+//! field is marked `#[serde_tuplex(lenient)]`; the trait docs show an implementation.
+//!
+//! ## Custom parsers
+//!
+//! `#[serde_tuplex(parse_with = "path")]` keeps the "number or string" intake but replaces
+//! the parse step. The function takes `&LenientValue`, `&str` or `f64` and returns
+//! `Result<T, E>` with `E: Display`:
+//!
+//! ```
+//! use serde_tuplex::Lenient;
+//!
+//! fn kilo(s: &str) -> Result<u64, std::num::ParseIntError> {
+//!     match s.strip_suffix('k') {
+//!         Some(n) => n.parse::<u64>().map(|v| v * 1000),
+//!         None => s.parse(),
+//!     }
+//! }
+//!
+//! #[derive(Lenient)]
+//! struct Stats {
+//!     #[serde_tuplex(parse_with = "kilo")]
+//!     volume: u64,    // Accepts 500, "500" or "2k"
+//! }
+//! ```
+//!
+//! # Crate features
+//!
+//! - `arbitrary_precision` - Enables serde_json's `arbitrary_precision` and reads numbers from
+//!   their exact digits, so big integers and long decimals stay lossless through [`lenient`],
+//!   [`Lenient`] and [`TupleLenient`]. [`LenientParse::parse_number`] receives the text.
+//! - `chrono` - Lenient `chrono::DateTime<Utc>` fields from epoch numbers or RFC 3339 strings.
+//! - `time` - Lenient `time::OffsetDateTime` fields from epoch numbers or RFC 3339 strings.
+//! - `rust_decimal` / `bigdecimal` - Lenient `Decimal` / `BigDecimal` fields, read from their
+//!   exact digits.
+//! - `num-bigint` / `primitive-types` - Lenient `BigInt`/`BigUint` / `U256` fields from numbers,
+//!   decimal strings and `0x` strings.
 
+#[cfg(any(feature = "num-bigint", feature = "primitive-types"))]
+mod bigint;
+mod bytes;
 mod de;
+#[cfg(any(feature = "rust_decimal", feature = "bigdecimal"))]
+mod decimal;
+mod duration;
+mod format;
 mod internal;
+mod normalize;
+mod number;
+mod parse;
+mod percent;
+mod ser;
+mod timestamp;
+mod value;
 
-pub use de::{lenient, lenient_option};
-pub use serde_tuplex_derive::{Lenient, Tuple, TupleLenient};
+pub use de::{
+    lenient, lenient_bool, lenient_bool_option, lenient_bytes, lenient_bytes_option,
+    lenient_duration, lenient_duration_option, lenient_option, lenient_percent,
+    lenient_percent_option, lenient_radix, lenient_radix_option, lenient_string,
+};
+pub use duration::DurationUnit;
+pub use format::NumberFormat;
+pub use normalize::Normalize;
+pub use number::{LenientNumber, NumberSource};
+pub use parse::{LenientParse, RadixParse};
+pub use percent::PercentMode;
+pub use ser::{
+    serialize_as_number, serialize_as_number_option, serialize_as_string,
+    serialize_as_string_option, serialize_bytes_human, serialize_bytes_si,
+    serialize_duration_human, serialize_duration_iso8601, serialize_hex, serialize_hex_option,
+    serialize_non_finite, serialize_non_finite_option, serialize_percent, serialize_percent_option,
+};
+pub use serde_tuplex_derive::{Lenient, LenientEnum, Tuple, TupleLenient};
+pub use timestamp::{Timestamp, TimestampUnit};
+pub use value::LenientValue;
 
 #[doc(hidden)]
 pub mod __private {
-    pub use crate::internal::{LenientValue, OptionalLenientValue};
+    pub use crate::internal::{
+        AsText, AsTextOption, BoundedParse, ByteSize, ByteSizeOption, ByteUnits, DurationField,
+        DurationRepr, EnumVariant, Epoch, EpochOption, FieldParser, FloatInput, Hex, HexOption,
+        NonFiniteString, NonFiniteStringOption, Percent, PercentOption, PercentRepr, StrInput,
+        TextRepr, ValueInput, check_range, match_enum_variant, parse_bool_with, parse_field_with,
+        reject_non_finite, serialize_discriminant,
+    };
+    pub use crate::value::LenientValue;
 }
@@ -0,0 +1,133 @@
+//! String clean-up applied before parsing.
+
+use std::borrow::Cow;
+use std::ops::BitOr;
+
+/// A set of clean-up steps applied to string input before parsing.
+///
+/// | Step            | Effect                                                  |
+/// |-----------------|---------------------------------------------------------|
+/// | `TRIM`          | `" 42\n"` -> `"42"`                                     |
+/// | `PLUS_SIGN`     | `"+42"` -> `"42"`                                       |
+/// | `UNICODE_MINUS` | `"−5"` (U+2212), `"－5"` (U+FF0D) -> `"-5"`             |
+/// | `FULL_WIDTH`    | `"４２"` -> `"42"`, also `＋` and `．`                   |
+/// | `UNDERSCORES`   | `"1_000"` -> `"1000"`, only between digits              |
+///
+/// Primitive numbers use [`DEFAULT`](Self::DEFAULT), which is every step; `bool` uses `TRIM`;
+/// other types use [`NONE`](Self::NONE) unless their [`LenientParse::NORMALIZE`] says
+/// otherwise. Fields override it with `#[serde_tuplex(normalize(trim, plus_sign))]` or
+/// `#[serde_tuplex(normalize = "none")]`, on the field or the struct.
+///
+/// [`LenientParse::NORMALIZE`]: crate::LenientParse::NORMALIZE
+///
+/// # Examples
+///
+/// ```
+/// use serde_tuplex::Normalize;
+///
+/// assert_eq!(Normalize::DEFAULT.apply(" +1_000\n"), "1000");
+/// assert_eq!(Normalize::DEFAULT.apply("−５"), "-5");
+/// assert_eq!(Normalize::TRIM.apply(" +1_000 "), "+1_000");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Normalize(u8);
+
+impl Normalize {
+    /// No clean-up.
+    pub const NONE: Normalize = Normalize(0);
+    /// Remove surrounding whitespace.
+    pub const TRIM: Normalize = Normalize(1);
+    /// Drop a leading `+`.
+    pub const PLUS_SIGN: Normalize = Normalize(1 << 1);
+    /// Map U+2212 MINUS SIGN, U+FE63 and U+FF0D to `-`.
+    pub const UNICODE_MINUS: Normalize = Normalize(1 << 2);
+    /// Map full-width digits, `＋` and `．` to ASCII.
+    pub const FULL_WIDTH: Normalize = Normalize(1 << 3);
+    /// Remove `_` between two digits, counting `a`-`f` as digits only after a `0x` prefix.
+    pub const UNDERSCORES: Normalize = Normalize(1 << 4);
+    /// The steps used for primitive numbers: all of the above.
+    pub const DEFAULT: Normalize = Normalize(0b1_1111);
+
+    /// Steps in either set.
+    pub const fn union(self, other: Normalize) -> Normalize {
+        Normalize(self.0 | other.0)
+    }
+
+    /// Whether every step of `other` is in this set.
+    pub const fn contains(self, other: Normalize) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Apply the steps to `s`. Borrows when nothing but trimming is needed.
+    pub fn apply<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        if *self == Normalize::NONE {
+            return Cow::Borrowed(s);
+        }
+        let s = if self.contains(Normalize::TRIM) {
+            s.trim()
+        } else {
+            s
+        };
+        let needs_rewrite = !s.is_ascii()
+            || (self.contains(Normalize::PLUS_SIGN) && s.starts_with('+'))
+            || (self.contains(Normalize::UNDERSCORES) && s.contains('_'));
+        if !needs_rewrite {
+            return Cow::Borrowed(s);
+        }
+
+        let mapped: Vec<char> = s.chars().map(|c| self.map_char(c)).collect();
+        // Hex digits only count as digits after a `0x` prefix, so `"1_e5"` keeps its `_`.
+        let unsigned = mapped.strip_prefix(&['+']).or(mapped.strip_prefix(&['-']));
+        let is_hex = matches!(unsigned.unwrap_or(&mapped), ['0', 'x' | 'X', ..]);
+        let is_digit = |c: &char| {
+            if is_hex {
+                c.is_ascii_hexdigit()
+            } else {
+                c.is_ascii_digit()
+            }
+        };
+        let mut out = String::with_capacity(s.len());
+        for (i, &c) in mapped.iter().enumerate() {
+            let skip = match c {
+                '+' => i == 0 && self.contains(Normalize::PLUS_SIGN),
+                '_' => {
+                    self.contains(Normalize::UNDERSCORES)
+                        && i > 0
+                        && is_digit(&mapped[i - 1])
+                        && mapped.get(i + 1).is_some_and(is_digit)
+                }
+                _ => false,
+            };
+            if !skip {
+                out.push(c);
+            }
+        }
+        Cow::Owned(out)
+    }
+
+    fn map_char(&self, c: char) -> char {
+        match c {
+            '\u{2212}' | '\u{fe63}' | '\u{ff0d}' if self.contains(Normalize::UNICODE_MINUS) => '-',
+            '\u{ff10}'..='\u{ff19}' if self.contains(Normalize::FULL_WIDTH) => {
+                char::from(b'0' + (c as u32 - 0xff10) as u8)
+            }
+            '\u{ff0b}' if self.contains(Normalize::FULL_WIDTH) => '+',
+            '\u{ff0e}' if self.contains(Normalize::FULL_WIDTH) => '.',
+            _ => c,
+        }
+    }
+}
+
+impl Default for Normalize {
+    fn default() -> Self {
+        Normalize::DEFAULT
+    }
+}
+
+impl BitOr for Normalize {
+    type Output = Normalize;
+
+    fn bitor(self, rhs: Normalize) -> Normalize {
+        self.union(rhs)
+    }
+}
@@ -0,0 +1,244 @@
+//! Numbers that remember how they were written.
+
+use crate::value::{LenientValue, serialize_number_text};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// Where a [`LenientNumber`] came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NumberSource {
+    /// A string such as `"0012"`.
+    String,
+    /// A native number such as `12` or `1e3`.
+    Native,
+}
+
+/// A number accepted from either a number or a numeric string, keeping the original text.
+///
+/// Useful for audit trails that must record exactly what the producer sent while still
+/// treating the value as a number. Serializes back in its original form: strings stay
+/// strings, native numbers stay numbers.
+///
+/// Native floats are recorded in Rust's shortest round-trip form (`1.0`, `1e300`); with the
+/// `arbitrary_precision` feature the exact input digits are kept instead.
+///
+/// # Examples
+///
+/// ```
+/// use serde_tuplex::{LenientNumber, NumberSource};
+///
+/// let n: LenientNumber = serde_json::from_str(r#""0012""#).unwrap();
+/// assert_eq!(n.source(), NumberSource::String);
+/// assert_eq!(n.raw(), "0012");
+/// assert_eq!(n.to_u64(), Some(12));
+/// assert_eq!(serde_json::to_string(&n).unwrap(), r#""0012""#);
+///
+/// let n: LenientNumber = serde_json::from_str(r#""1e3""#).unwrap();
+/// assert_eq!(n.to_decimal_str().as_deref(), Some("1000"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LenientNumber {
+    raw: String,
+    source: NumberSource,
+}
+
+impl LenientNumber {
+    /// Longest plain-notation expansion produced by [`to_decimal_str`](Self::to_decimal_str).
+    pub const MAX_DECIMAL_DIGITS: usize = 1024;
+
+    /// Build from a string, validating that it is a decimal number.
+    pub fn from_string(raw: impl Into<String>) -> Result<Self, String> {
+        let raw = raw.into();
+        if DecimalParts::parse(&raw).is_none() {
+            return Err(format!("invalid number {:?}", raw));
+        }
+        Ok(LenientNumber {
+            raw,
+            source: NumberSource::String,
+        })
+    }
+
+    /// The text exactly as received (or as formatted, for native numbers).
+    pub fn raw(&self) -> &str {
+        &self.raw
+    }
+
+    /// Whether the value arrived as a string or a native number.
+    pub fn source(&self) -> NumberSource {
+        self.source
+    }
+
+    /// Convert to `u64` if the value is integral and in range.
+    pub fn to_u64(&self) -> Option<u64> {
+        self.integer_str()?.parse().ok()
+    }
+
+    /// Convert to `i64` if the value is integral and in range.
+    pub fn to_i64(&self) -> Option<i64> {
+        self.integer_str()?.parse().ok()
+    }
+
+    /// Convert to `u128` if the value is integral and in range.
+    pub fn to_u128(&self) -> Option<u128> {
+        self.integer_str()?.parse().ok()
+    }
+
+    /// Convert to `i128` if the value is integral and in range.
+    pub fn to_i128(&self) -> Option<i128> {
+        self.integer_str()?.parse().ok()
+    }
+
+    /// Convert to the nearest `f64`, or `None` if the value overflows to infinity.
+    pub fn to_f64(&self) -> Option<f64> {
+        let v: f64 = self.raw.parse().ok()?;
+        v.is_finite().then_some(v)
+    }
+
+    /// The value in plain decimal notation: no exponent, no redundant leading zeros.
+    ///
+    /// Trailing fractional zeros are kept (`"1.50"` stays `1.50`). Returns `None` if the
+    /// expansion would exceed [`MAX_DECIMAL_DIGITS`](Self::MAX_DECIMAL_DIGITS) digits.
+    pub fn to_decimal_str(&self) -> Option<String> {
+        DecimalParts::parse(&self.raw)?.to_plain()
+    }
+
+    /// Integer text if the value has no fractional part.
+    fn integer_str(&self) -> Option<String> {
+        let plain = self.to_decimal_str()?;
+        match plain.split_once('.') {
+            Some((int, frac)) if frac.bytes().all(|b| b == b'0') => Some(int.to_string()),
+            Some(_) => None,
+            None => Some(plain),
+        }
+    }
+
+    fn from_value(value: LenientValue<'_>) -> Result<Self, String> {
+        let (raw, source) = match value {
+            LenientValue::String(s) => return Self::from_string(s.into_owned()),
+            LenientValue::Number(s) => (s.into_owned(), NumberSource::Native),
+            LenientValue::I64(v) => (v.to_string(), NumberSource::Native),
+            LenientValue::U64(v) => (v.to_string(), NumberSource::Native),
+            LenientValue::I128(v) => (v.to_string(), NumberSource::Native),
+            LenientValue::U128(v) => (v.to_string(), NumberSource::Native),
+            LenientValue::F64(v) if v.is_finite() => (format!("{:?}", v), NumberSource::Native),
+            LenientValue::F64(v) => return Err(format!("{} is not a finite number", v)),
+            LenientValue::Bool(v) => return Err(format!("expected a number, found {}", v)),
+        };
+        Ok(LenientNumber { raw, source })
+    }
+}
+
+impl fmt::Display for LenientNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.raw)
+    }
+}
+
+impl<'de> Deserialize<'de> for LenientNumber {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        LenientNumber::from_value(LenientValue::deserialize(deserializer)?)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+impl Serialize for LenientNumber {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if self.source == NumberSource::String {
+            return serializer.serialize_str(&self.raw);
+        }
+        serialize_number_text(&self.raw, serializer)
+    }
+}
+
+/// A decimal number split into sign, digits and decimal point position.
+pub(crate) struct DecimalParts {
+    pub negative: bool,
+    /// All significant digits, integer part first.
+    pub digits: String,
+    /// Position of the decimal point within `digits` (may be negative or past the end).
+    pub point: i64,
+}
+
+impl DecimalParts {
+    /// Parse `[+-]digits[.digits][(e|E)[+-]digits]`.
+    pub fn parse(s: &str) -> Option<Self> {
+        let (negative, rest) = match s.as_bytes().first()? {
+            b'-' => (true, &s[1..]),
+            b'+' => (false, &s[1..]),
+            _ => (false, s),
+        };
+        let (mantissa, exponent) = match rest.find(['e', 'E']) {
+            Some(idx) => (&rest[..idx], Some(&rest[idx + 1..])),
+            None => (rest, None),
+        };
+        let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+        if int.is_empty() && frac.is_empty() || !is_digits(int) || !is_digits(frac) {
+            return None;
+        }
+        let exponent: i64 = match exponent {
+            Some(e) => {
+                let digits = e.strip_prefix(['+', '-']).unwrap_or(e);
+                if digits.is_empty() || !is_digits(digits) {
+                    return None;
+                }
+                // Saturate absurd exponents, parsed or not, so the point position below
+                // cannot overflow; `to_plain` rejects them anyway.
+                let bound = i64::MAX / 2;
+                let exponent: i64 =
+                    e.parse()
+                        .unwrap_or(if e.starts_with('-') { -bound } else { bound });
+                exponent.clamp(-bound, bound)
+            }
+            None => 0,
+        };
+        Some(DecimalParts {
+            negative,
+            digits: format!("{}{}", int, frac),
+            point: (int.len() as i64).checked_add(exponent)?,
+        })
+    }
+
+    /// Render in plain notation, or `None` if it would exceed [`LenientNumber::MAX_DECIMAL_DIGITS`].
+    pub fn to_plain(&self) -> Option<String> {
+        let len = self.digits.len() as i64;
+        let (int, frac) = if self.point <= 0 {
+            let zeros = usize::try_from(self.point.checked_neg()?).ok()?;
+            if zeros + self.digits.len() > LenientNumber::MAX_DECIMAL_DIGITS {
+                return None;
+            }
+            (
+                String::new(),
+                format!("{}{}", "0".repeat(zeros), self.digits),
+            )
+        } else if self.point >= len {
+            let zeros = usize::try_from(self.point - len).ok()?;
+            if zeros + self.digits.len() > LenientNumber::MAX_DECIMAL_DIGITS {
+                return None;
+            }
+            (
+                format!("{}{}", self.digits, "0".repeat(zeros)),
+                String::new(),
+            )
+        } else {
+            let (int, frac) = self.digits.split_at(self.point as usize);
+            (int.to_string(), frac.to_string())
+        };
+
+        let int = int.trim_start_matches('0');
+        let int = if int.is_empty() { "0" } else { int };
+        let is_zero = int == "0" && frac.bytes().all(|b| b == b'0');
+        let sign = if self.negative && !is_zero { "-" } else { "" };
+        Some(if frac.is_empty() {
+            format!("{}{}", sign, int)
+        } else {
+            format!("{}{}.{}", sign, int, frac)
+        })
+    }
+}
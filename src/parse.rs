@@ -0,0 +1,563 @@
+//! Conversion from lenient input to target types.
+
+use crate::internal::BoundedParse;
+use crate::normalize::Normalize;
+use crate::number::DecimalParts;
+use crate::value::LenientValue;
+use std::fmt;
+use std::num::{
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize, NonZeroU8,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize, Saturating, Wrapping,
+};
+use std::str::FromStr;
+
+/// Types that can be built from "number or string" input.
+///
+/// Each hook receives the value in the form the deserializer produced it. Only
+/// [`parse_str`](LenientParse::parse_str) is required; the numeric hooks default to
+/// parsing the number's `Display` form, so a type with a string format gets numbers for free
+/// and can override the hooks it can convert exactly. The primitive impls convert native
+/// numbers with checked casts and never allocate.
+///
+/// Used by [`lenient`](crate::lenient), [`lenient_option`](crate::lenient_option) and
+/// `#[serde_tuplex(lenient)]` fields.
+///
+/// # Examples
+///
+/// ```
+/// use serde_tuplex::{Lenient, LenientParse};
+///
+/// /// Price in cents.
+/// #[derive(Debug, PartialEq)]
+/// struct Price(i64);
+///
+/// impl LenientParse for Price {
+///     fn parse_i64(v: i64) -> Result<Self, String> {
+///         v.checked_mul(100).map(Price).ok_or_else(|| "price too large".to_string())
+///     }
+///
+///     fn parse_str(s: &str) -> Result<Self, String> {
+///         let (whole, frac) = s.split_once('.').unwrap_or((s, "0"));
+///         let cents = format!("{}{:0<2}", whole, frac);
+///         cents.parse().map(Price).map_err(|e| format!("invalid price: {}", e))
+///     }
+/// }
+///
+/// #[derive(Lenient)]
+/// struct Order {
+///     #[serde_tuplex(lenient)]
+///     total: Price,
+/// }
+///
+/// let order: Order = serde_json::from_str(r#"{"total": "12.5"}"#).unwrap();
+/// assert_eq!(order.total, Price(1250));
+/// ```
+pub trait LenientParse: Sized {
+    /// Clean-up applied to string input by [`LenientValue::parse`] before
+    /// [`parse_str`](LenientParse::parse_str). Primitive numbers use [`Normalize::DEFAULT`].
+    ///
+    /// [`LenientValue::parse`]: crate::LenientValue::parse
+    const NORMALIZE: Normalize = Normalize::NONE;
+
+    /// Convert a native signed integer.
+    fn parse_i64(v: i64) -> Result<Self, String> {
+        Self::parse_str(&v.to_string())
+    }
+
+    /// Convert a native unsigned integer.
+    fn parse_u64(v: u64) -> Result<Self, String> {
+        Self::parse_str(&v.to_string())
+    }
+
+    /// Convert a native 128-bit signed integer.
+    fn parse_i128(v: i128) -> Result<Self, String> {
+        Self::parse_str(&v.to_string())
+    }
+
+    /// Convert a native 128-bit unsigned integer.
+    fn parse_u128(v: u128) -> Result<Self, String> {
+        Self::parse_str(&v.to_string())
+    }
+
+    /// Convert a native float.
+    fn parse_f64(v: f64) -> Result<Self, String> {
+        Self::parse_str(&v.to_string())
+    }
+
+    /// Convert a native boolean.
+    fn parse_bool(v: bool) -> Result<Self, String> {
+        Self::parse_str(if v { "true" } else { "false" })
+    }
+
+    /// Convert string input.
+    fn parse_str(s: &str) -> Result<Self, String>;
+
+    /// Convert a native number given as its exact text, e.g. `12.50` or
+    /// `123456789012345678901234567890` under serde_json's `arbitrary_precision`.
+    fn parse_number(s: &str) -> Result<Self, String> {
+        Self::parse_str(s)
+    }
+}
+
+macro_rules! impl_lenient_parse_int {
+    ($($ty:ty),*) => {$(
+        impl LenientParse for $ty {
+            const NORMALIZE: Normalize = Normalize::DEFAULT;
+
+            fn parse_i64(v: i64) -> Result<Self, String> {
+                <$ty>::try_from(v).map_err(|_| out_of_range(v, stringify!($ty)))
+            }
+
+            fn parse_u64(v: u64) -> Result<Self, String> {
+                <$ty>::try_from(v).map_err(|_| out_of_range(v, stringify!($ty)))
+            }
+
+            fn parse_i128(v: i128) -> Result<Self, String> {
+                <$ty>::try_from(v).map_err(|_| out_of_range(v, stringify!($ty)))
+            }
+
+            fn parse_u128(v: u128) -> Result<Self, String> {
+                <$ty>::try_from(v).map_err(|_| out_of_range(v, stringify!($ty)))
+            }
+
+            fn parse_f64(v: f64) -> Result<Self, String> {
+                if v.fract() != 0.0 {
+                    return Err(format!("{} is not an integer", v));
+                }
+                // `MAX as f64 + 1.0` is exact or rounds up to the next power of two,
+                // so the upper bound is exclusive for every width.
+                if v >= <$ty>::MIN as f64 && v < <$ty>::MAX as f64 + 1.0 {
+                    Ok(v as $ty)
+                } else {
+                    Err(out_of_range(v, stringify!($ty)))
+                }
+            }
+
+            fn parse_str(s: &str) -> Result<Self, String> {
+                match s.parse::<$ty>() {
+                    Ok(v) => Ok(v),
+                    Err(_) if s.contains(['e', 'E']) => parse_integral(s, stringify!($ty)),
+                    Err(e) => Err(invalid_str(s, stringify!($ty), e)),
+                }
+            }
+
+            fn parse_number(s: &str) -> Result<Self, String> {
+                // Float syntax such as `42.0` or `1e3` converts when exactly integral.
+                match s.parse::<$ty>() {
+                    Ok(v) => Ok(v),
+                    Err(_) if s.contains(['.', 'e', 'E']) => parse_integral(s, stringify!($ty)),
+                    Err(e) => Err(invalid_str(s, stringify!($ty), e)),
+                }
+            }
+        }
+    )*};
+}
+
+impl_lenient_parse_int!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+macro_rules! impl_lenient_parse_float {
+    ($($ty:ty),*) => {$(
+        impl LenientParse for $ty {
+            const NORMALIZE: Normalize = Normalize::DEFAULT;
+
+            fn parse_i64(v: i64) -> Result<Self, String> {
+                Ok(v as $ty)
+            }
+
+            fn parse_u64(v: u64) -> Result<Self, String> {
+                Ok(v as $ty)
+            }
+
+            fn parse_i128(v: i128) -> Result<Self, String> {
+                Ok(v as $ty)
+            }
+
+            fn parse_u128(v: u128) -> Result<Self, String> {
+                Ok(v as $ty)
+            }
+
+            fn parse_f64(v: f64) -> Result<Self, String> {
+                Ok(v as $ty)
+            }
+
+            fn parse_str(s: &str) -> Result<Self, String> {
+                s.parse().map_err(|e| invalid_str(s, stringify!($ty), e))
+            }
+        }
+    )*};
+}
+
+impl_lenient_parse_float!(f32, f64);
+
+macro_rules! impl_lenient_parse_from_str {
+    ($($ty:ty),*) => {$(
+        impl LenientParse for $ty {
+            fn parse_str(s: &str) -> Result<Self, String> {
+                s.parse().map_err(|e| invalid_str(s, stringify!($ty), e))
+            }
+        }
+    )*};
+}
+
+impl_lenient_parse_from_str!(char);
+
+/// Strings the `bool` impl reads as `true`, compared case-insensitively.
+///
+/// The derive keeps a copy of both lists to reject overlapping `true_values`/`false_values`.
+pub(crate) const TRUE_STRINGS: &[&str] = &["true", "t", "yes", "y", "on", "1"];
+
+/// Strings the `bool` impl reads as `false`, compared case-insensitively.
+pub(crate) const FALSE_STRINGS: &[&str] = &["false", "f", "no", "n", "off", "0"];
+
+/// Look `s` up in a boolean vocabulary, ignoring ASCII case.
+pub(crate) fn parse_bool_str(
+    s: &str,
+    true_values: &[&str],
+    false_values: &[&str],
+) -> Result<bool, String> {
+    if true_values.iter().any(|t| t.eq_ignore_ascii_case(s)) {
+        Ok(true)
+    } else if false_values.iter().any(|f| f.eq_ignore_ascii_case(s)) {
+        Ok(false)
+    } else {
+        Err(format!(
+            "invalid bool {:?}: expected one of {} or {}",
+            s,
+            true_values.join(", "),
+            false_values.join(", ")
+        ))
+    }
+}
+
+/// Accepts native booleans, `1`/`0` and the case-insensitive strings
+/// `true`/`t`/`yes`/`y`/`on`/`1` and `false`/`f`/`no`/`n`/`off`/`0`.
+impl LenientParse for bool {
+    const NORMALIZE: Normalize = Normalize::TRIM;
+
+    fn parse_bool(v: bool) -> Result<Self, String> {
+        Ok(v)
+    }
+
+    fn parse_i64(v: i64) -> Result<Self, String> {
+        Self::parse_i128(i128::from(v))
+    }
+
+    fn parse_u64(v: u64) -> Result<Self, String> {
+        Self::parse_i128(i128::from(v))
+    }
+
+    fn parse_i128(v: i128) -> Result<Self, String> {
+        match v {
+            1 => Ok(true),
+            0 => Ok(false),
+            _ => Err(format!("{} is not a valid bool, expected 1 or 0", v)),
+        }
+    }
+
+    fn parse_u128(v: u128) -> Result<Self, String> {
+        i128::try_from(v)
+            .map_err(|_| format!("{} is not a valid bool, expected 1 or 0", v))
+            .and_then(Self::parse_i128)
+    }
+
+    fn parse_f64(v: f64) -> Result<Self, String> {
+        if v == 1.0 {
+            Ok(true)
+        } else if v == 0.0 {
+            Ok(false)
+        } else {
+            Err(format!("{} is not a valid bool, expected 1 or 0", v))
+        }
+    }
+
+    fn parse_str(s: &str) -> Result<Self, String> {
+        parse_bool_str(s, TRUE_STRINGS, FALSE_STRINGS)
+    }
+}
+
+/// Renders numbers canonically: integers in decimal, floats in their shortest round-trip form
+/// without an exponent (`3.0` becomes `"3"`), booleans as `"true"`/`"false"`. Exact number
+/// text from `arbitrary_precision` is kept verbatim.
+impl LenientParse for String {
+    fn parse_i64(v: i64) -> Result<Self, String> {
+        Ok(v.to_string())
+    }
+
+    fn parse_u64(v: u64) -> Result<Self, String> {
+        Ok(v.to_string())
+    }
+
+    fn parse_i128(v: i128) -> Result<Self, String> {
+        Ok(v.to_string())
+    }
+
+    fn parse_u128(v: u128) -> Result<Self, String> {
+        Ok(v.to_string())
+    }
+
+    fn parse_f64(v: f64) -> Result<Self, String> {
+        Ok(v.to_string())
+    }
+
+    fn parse_bool(v: bool) -> Result<Self, String> {
+        Ok(v.to_string())
+    }
+
+    fn parse_str(s: &str) -> Result<Self, String> {
+        Ok(s.to_string())
+    }
+}
+
+impl LenientParse for Box<str> {
+    fn parse_i64(v: i64) -> Result<Self, String> {
+        String::parse_i64(v).map(String::into_boxed_str)
+    }
+
+    fn parse_u64(v: u64) -> Result<Self, String> {
+        String::parse_u64(v).map(String::into_boxed_str)
+    }
+
+    fn parse_i128(v: i128) -> Result<Self, String> {
+        String::parse_i128(v).map(String::into_boxed_str)
+    }
+
+    fn parse_u128(v: u128) -> Result<Self, String> {
+        String::parse_u128(v).map(String::into_boxed_str)
+    }
+
+    fn parse_f64(v: f64) -> Result<Self, String> {
+        String::parse_f64(v).map(String::into_boxed_str)
+    }
+
+    fn parse_bool(v: bool) -> Result<Self, String> {
+        String::parse_bool(v).map(String::into_boxed_str)
+    }
+
+    fn parse_str(s: &str) -> Result<Self, String> {
+        Ok(s.into())
+    }
+}
+
+macro_rules! impl_lenient_parse_nonzero {
+    ($($ty:ident => $inner:ty),*) => {$(
+        impl LenientParse for $ty {
+            const NORMALIZE: Normalize = <$inner>::NORMALIZE;
+
+            fn parse_i64(v: i64) -> Result<Self, String> {
+                <$inner>::parse_i64(v).and_then(non_zero)
+            }
+
+            fn parse_u64(v: u64) -> Result<Self, String> {
+                <$inner>::parse_u64(v).and_then(non_zero)
+            }
+
+            fn parse_i128(v: i128) -> Result<Self, String> {
+                <$inner>::parse_i128(v).and_then(non_zero)
+            }
+
+            fn parse_u128(v: u128) -> Result<Self, String> {
+                <$inner>::parse_u128(v).and_then(non_zero)
+            }
+
+            fn parse_f64(v: f64) -> Result<Self, String> {
+                <$inner>::parse_f64(v).and_then(non_zero)
+            }
+
+            fn parse_str(s: &str) -> Result<Self, String> {
+                <$inner>::parse_str(s).and_then(non_zero)
+            }
+
+            fn parse_number(s: &str) -> Result<Self, String> {
+                <$inner>::parse_number(s).and_then(non_zero)
+            }
+
+            fn parse_bool(v: bool) -> Result<Self, String> {
+                <$inner>::parse_bool(v).and_then(non_zero)
+            }
+        }
+    )*};
+}
+
+impl_lenient_parse_nonzero!(
+    NonZeroU8 => u8,
+    NonZeroU16 => u16,
+    NonZeroU32 => u32,
+    NonZeroU64 => u64,
+    NonZeroU128 => u128,
+    NonZeroUsize => usize,
+    NonZeroI8 => i8,
+    NonZeroI16 => i16,
+    NonZeroI32 => i32,
+    NonZeroI64 => i64,
+    NonZeroI128 => i128,
+    NonZeroIsize => isize
+);
+
+/// Integers that also accept `0x`, `0o` and `0b` prefixed strings.
+///
+/// Implemented for all primitive integers. Used by [`lenient_radix`](crate::lenient_radix),
+/// [`serialize_hex`](crate::serialize_hex) and `#[serde_tuplex(radix_prefix)]` fields.
+///
+/// # Examples
+///
+/// ```
+/// use serde_tuplex::RadixParse;
+///
+/// assert_eq!(u32::parse_radix_str("0x1f"), Ok(31));
+/// assert_eq!(i16::parse_radix_str("-0b1010"), Ok(-10));
+/// assert_eq!(u64::parse_radix_str("0xdead_beef"), Ok(0xdead_beef));
+/// assert_eq!(255u8.to_hex_string(), "0xff");
+/// ```
+pub trait RadixParse: LenientParse {
+    /// Parse `[+-][0x|0o|0b]digits`, ignoring `_` between digits. No prefix means decimal.
+    fn parse_radix_str(s: &str) -> Result<Self, String>;
+
+    /// Format as `0x...`, or `-0x...` for negative values.
+    fn to_hex_string(&self) -> String;
+}
+
+/// Split `[+-][0x|0o|0b]digits` into sign, radix and the digits without `_` separators, which
+/// must sit between two digits as with [`Normalize`].
+pub(crate) fn split_radix(s: &str) -> Result<(bool, u32, String), String> {
+    let (negative, rest) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let (radix, digits) = match rest.get(..2) {
+        Some("0x" | "0X") => (16, &rest[2..]),
+        Some("0o" | "0O") => (8, &rest[2..]),
+        Some("0b" | "0B") => (2, &rest[2..]),
+        _ => (10, rest),
+    };
+    if digits.starts_with('_') || digits.ends_with('_') || digits.contains("__") {
+        return Err("misplaced `_` separator".to_string());
+    }
+    let digits: String = digits.chars().filter(|&c| c != '_').collect();
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_alphanumeric()) {
+        return Err("invalid digit found in string".to_string());
+    }
+    Ok((negative, radix, digits))
+}
+
+/// Split `[+-][0x|0o|0b]digits` into sign and magnitude.
+fn parse_radix_magnitude(s: &str) -> Result<(bool, u128), String> {
+    let (negative, radix, digits) = split_radix(s)?;
+    let magnitude = u128::from_str_radix(&digits, radix).map_err(|e| e.to_string())?;
+    Ok((negative, magnitude))
+}
+
+macro_rules! impl_radix_parse {
+    ($($ty:ty),*) => {$(
+        impl RadixParse for $ty {
+            fn parse_radix_str(s: &str) -> Result<Self, String> {
+                let (negative, magnitude) =
+                    parse_radix_magnitude(s).map_err(|e| invalid_str(s, stringify!($ty), e))?;
+                let value = if negative {
+                    0i128
+                        .checked_sub_unsigned(magnitude)
+                        .and_then(|v| <$ty>::try_from(v).ok())
+                } else {
+                    <$ty>::try_from(magnitude).ok()
+                };
+                value.ok_or_else(|| out_of_range(s, stringify!($ty)))
+            }
+
+            fn to_hex_string(&self) -> String {
+                match i128::try_from(*self) {
+                    Ok(v) if v < 0 => format!("-0x{:x}", v.unsigned_abs()),
+                    _ => format!("0x{:x}", self),
+                }
+            }
+        }
+    )*};
+}
+
+impl_radix_parse!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+/// Plain integer text of decimal or exponent notation, e.g. `"1.5e3"` -> `"1500"`.
+///
+/// Errors if the value has a fractional part or expands past
+/// [`LenientNumber::MAX_DECIMAL_DIGITS`](crate::LenientNumber::MAX_DECIMAL_DIGITS).
+pub(crate) fn integral_text(s: &str, ty: &str) -> Result<String, String> {
+    let parts = DecimalParts::parse(s).ok_or_else(|| format!("invalid {} {:?}", ty, s))?;
+    if parts.digits.bytes().all(|b| b == b'0') {
+        return Ok("0".to_string());
+    }
+    let plain = parts.to_plain().ok_or_else(|| out_of_range(s, ty))?;
+    match plain.split_once('.') {
+        Some((int, frac)) if frac.bytes().all(|b| b == b'0') => Ok(int.to_string()),
+        Some(_) => Err(format!("{} is not an integer", s)),
+        None => Ok(plain),
+    }
+}
+
+fn parse_integral<T: FromStr>(s: &str, ty: &str) -> Result<T, String> {
+    integral_text(s, ty)?
+        .parse()
+        .map_err(|_| out_of_range(s, ty))
+}
+
+pub(crate) fn out_of_range(v: impl fmt::Display, ty: &str) -> String {
+    format!("{} is out of range for {}", v, ty)
+}
+
+pub(crate) fn invalid_str(s: &str, ty: &str, err: impl fmt::Display) -> String {
+    format!("invalid {} {:?}: {}", ty, s, err)
+}
+
+fn non_zero<T, N: TryFrom<T>>(v: T) -> Result<N, String> {
+    N::try_from(v).map_err(|_| "value must be non-zero".to_string())
+}
+
+/// `Wrapping<int>` wraps out-of-range input and `Saturating<int>` clamps it, matching what the
+/// types do with arithmetic; fractions are still an error.
+macro_rules! impl_lenient_parse_wrapper {
+    ($wrapper:ident => $parse:ident: $($ty:ty),*) => {$(
+        impl LenientParse for $wrapper<$ty> {
+            const NORMALIZE: Normalize = <$ty>::NORMALIZE;
+
+            fn parse_i64(v: i64) -> Result<Self, String> {
+                <$ty>::$parse(&LenientValue::I64(v)).map($wrapper)
+            }
+
+            fn parse_u64(v: u64) -> Result<Self, String> {
+                <$ty>::$parse(&LenientValue::U64(v)).map($wrapper)
+            }
+
+            fn parse_i128(v: i128) -> Result<Self, String> {
+                <$ty>::$parse(&LenientValue::I128(v)).map($wrapper)
+            }
+
+            fn parse_u128(v: u128) -> Result<Self, String> {
+                <$ty>::$parse(&LenientValue::U128(v)).map($wrapper)
+            }
+
+            fn parse_f64(v: f64) -> Result<Self, String> {
+                <$ty>::$parse(&LenientValue::F64(v)).map($wrapper)
+            }
+
+            fn parse_str(s: &str) -> Result<Self, String> {
+                <$ty>::$parse(&LenientValue::String(s.into())).map($wrapper)
+            }
+
+            fn parse_number(s: &str) -> Result<Self, String> {
+                <$ty>::$parse(&LenientValue::Number(s.into())).map($wrapper)
+            }
+
+            fn parse_bool(v: bool) -> Result<Self, String> {
+                <$ty>::parse_bool(v).map($wrapper)
+            }
+        }
+    )*};
+}
+
+impl_lenient_parse_wrapper!(
+    Wrapping => parse_wrapping: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+impl_lenient_parse_wrapper!(
+    Saturating => parse_saturating: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
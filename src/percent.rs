@@ -0,0 +1,44 @@
+//! Percentages such as `"12.5%"` read as ratios.
+
+use crate::number::DecimalParts;
+use crate::parse::LenientParse;
+use std::fmt;
+
+/// How bare numbers (without a `%`) are read by a percent field, and written back.
+///
+/// Selected with `#[serde_tuplex(percent = "ratio")]` or `percent = "percent"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PercentMode {
+    /// `0.125` is already a ratio; only `"12.5%"` is divided by 100.
+    Ratio,
+    /// `12.5` means 12.5 percent, like `"12.5%"`.
+    Percent,
+}
+
+/// Move the decimal point of a decimal string, e.g. `("12.5", -2)` -> `"0.125"`.
+fn shift_decimal(s: &str, places: i64) -> Option<String> {
+    let mut parts = DecimalParts::parse(s)?;
+    parts.point = parts.point.checked_add(places)?;
+    parts.to_plain()
+}
+
+/// Divide a decimal string by 100 and parse it.
+pub(crate) fn parse_hundredths<T: LenientParse>(s: &str) -> Result<T, String> {
+    let ratio = shift_decimal(s, -2).ok_or_else(|| format!("invalid percentage {:?}", s))?;
+    T::parse_str(&ratio)
+}
+
+/// Parse `"12.5%"`, or a bare number read according to `mode`.
+pub(crate) fn parse_percent_str<T: LenientParse>(s: &str, mode: PercentMode) -> Result<T, String> {
+    match s.trim().strip_suffix('%') {
+        Some(number) => parse_hundredths(number.trim_end()),
+        None if mode == PercentMode::Percent => parse_hundredths(s.trim()),
+        None => T::parse_str(s),
+    }
+}
+
+/// A ratio in percent, e.g. `0.125` -> `"12.5"`.
+pub(crate) fn to_percent_text(ratio: impl fmt::Display) -> Result<String, String> {
+    let text = ratio.to_string();
+    shift_decimal(&text, 2).ok_or_else(|| format!("{} cannot be written as a percentage", text))
+}
@@ -0,0 +1,314 @@
+use crate::bytes::{ByteUnits, format_byte_size};
+use crate::duration::{format_human, format_iso8601};
+use crate::internal::{AsText, TextRepr};
+use crate::parse::RadixParse;
+use crate::percent::to_percent_text;
+use crate::value::serialize_number_text;
+use serde::{Serialize, Serializer};
+use std::fmt;
+use std::time::Duration;
+
+/// Serialize an integer as a `0x` prefixed hex string.
+///
+/// The counterpart of [`lenient_radix`](crate::lenient_radix). Use with
+/// `#[serde(serialize_with = "serialize_hex")]`.
+///
+/// # Examples
+///
+/// ```
+/// use serde::Serialize;
+/// use serde_tuplex::serialize_hex;
+///
+/// #[derive(Serialize)]
+/// struct Register {
+///     #[serde(serialize_with = "serialize_hex")]
+///     address: u32,
+/// }
+///
+/// let json = serde_json::to_string(&Register { address: 31 }).unwrap();
+/// assert_eq!(json, r#"{"address":"0x1f"}"#);
+/// ```
+pub fn serialize_hex<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: RadixParse,
+{
+    serializer.serialize_str(&value.to_hex_string())
+}
+
+/// Serialize `Option<T>` as a hex string or `null`.
+pub fn serialize_hex_option<S, T>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: RadixParse,
+{
+    match value {
+        Some(v) => serializer.serialize_some(&v.to_hex_string()),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Serialize a float, writing NaN and infinities as `"NaN"`, `"Infinity"` and `"-Infinity"`.
+///
+/// This is the protobuf JSON convention; finite values are written as numbers. Use with
+/// `#[serde(serialize_with = "serialize_non_finite")]`.
+///
+/// # Examples
+///
+/// ```
+/// use serde::Serialize;
+/// use serde_tuplex::serialize_non_finite;
+///
+/// #[derive(Serialize)]
+/// struct Sample {
+///     #[serde(serialize_with = "serialize_non_finite")]
+///     value: f64,
+/// }
+///
+/// let json = serde_json::to_string(&Sample { value: f64::NAN }).unwrap();
+/// assert_eq!(json, r#"{"value":"NaN"}"#);
+/// let json = serde_json::to_string(&Sample { value: 1.5 }).unwrap();
+/// assert_eq!(json, r#"{"value":1.5}"#);
+/// ```
+pub fn serialize_non_finite<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize + Into<f64> + Copy,
+{
+    let v: f64 = (*value).into();
+    if v.is_nan() {
+        serializer.serialize_str("NaN")
+    } else if v == f64::INFINITY {
+        serializer.serialize_str("Infinity")
+    } else if v == f64::NEG_INFINITY {
+        serializer.serialize_str("-Infinity")
+    } else {
+        value.serialize(serializer)
+    }
+}
+
+/// Serialize `Option<T>` with [`serialize_non_finite`], or `null`.
+pub fn serialize_non_finite_option<S, T>(
+    value: &Option<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize + Into<f64> + Copy,
+{
+    match value {
+        Some(v) => serialize_non_finite(v, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Serialize a [`Duration`] as a compact string such as `"1m30s"` or `"250ms"`.
+///
+/// The counterpart of [`lenient_duration`](crate::lenient_duration).
+///
+/// # Examples
+///
+/// ```
+/// use serde::Serialize;
+/// use serde_tuplex::serialize_duration_human;
+/// use std::time::Duration;
+///
+/// #[derive(Serialize)]
+/// struct Config {
+///     #[serde(serialize_with = "serialize_duration_human")]
+///     timeout: Duration,
+/// }
+///
+/// let json = serde_json::to_string(&Config { timeout: Duration::from_millis(90_500) }).unwrap();
+/// assert_eq!(json, r#"{"timeout":"1m30s500ms"}"#);
+/// ```
+pub fn serialize_duration_human<S: Serializer>(
+    value: &Duration,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&format_human(*value))
+}
+
+/// Serialize a [`Duration`] in ISO-8601 form such as `"PT1M30.5S"`.
+pub fn serialize_duration_iso8601<S: Serializer>(
+    value: &Duration,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&format_iso8601(*value))
+}
+
+/// Serialize a byte count with IEC units, such as `"1MiB"` or `"1.5KiB"`.
+///
+/// The largest unit that keeps the value exact within three decimals is used; other counts
+/// are written as `"1025B"`. The counterpart of [`lenient_bytes`](crate::lenient_bytes).
+///
+/// # Examples
+///
+/// ```
+/// use serde::Serialize;
+/// use serde_tuplex::serialize_bytes_human;
+///
+/// #[derive(Serialize)]
+/// struct Limits {
+///     #[serde(serialize_with = "serialize_bytes_human")]
+///     memory: u64,
+/// }
+///
+/// let json = serde_json::to_string(&Limits { memory: 1_048_576 }).unwrap();
+/// assert_eq!(json, r#"{"memory":"1MiB"}"#);
+/// ```
+pub fn serialize_bytes_human<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Copy + TryInto<u128>,
+{
+    serialize_bytes_with(*value, ByteUnits::Iec, serializer)
+}
+
+/// Serialize a byte count with SI units, such as `"1MB"` or `"1.5kB"`.
+pub fn serialize_bytes_si<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Copy + TryInto<u128>,
+{
+    serialize_bytes_with(*value, ByteUnits::Si, serializer)
+}
+
+pub(crate) fn serialize_bytes_with<S, T>(
+    value: T,
+    units: ByteUnits,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: TryInto<u128>,
+{
+    let bytes = value
+        .try_into()
+        .map_err(|_| serde::ser::Error::custom("byte sizes cannot be negative"))?;
+    serializer.serialize_str(&format_byte_size(bytes, units))
+}
+
+/// Serialize a ratio as a percentage string, such as `"12.5%"` for `0.125`.
+///
+/// The decimal point is moved in the text, so `0.333` is written as `"33.3%"`. The
+/// counterpart of [`lenient_percent`](crate::lenient_percent); non-finite values are errors.
+///
+/// # Examples
+///
+/// ```
+/// use serde::Serialize;
+/// use serde_tuplex::serialize_percent;
+///
+/// #[derive(Serialize)]
+/// struct Stats {
+///     #[serde(serialize_with = "serialize_percent")]
+///     hit_rate: f64,
+/// }
+///
+/// let json = serde_json::to_string(&Stats { hit_rate: 0.125 }).unwrap();
+/// assert_eq!(json, r#"{"hit_rate":"12.5%"}"#);
+/// ```
+pub fn serialize_percent<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: fmt::Display,
+{
+    serializer.serialize_str(&percent_string(value)?)
+}
+
+/// Serialize `Option<T>` as a percentage string or `null`.
+pub fn serialize_percent_option<S, T>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: fmt::Display,
+{
+    match value {
+        Some(v) => serializer.serialize_some(&percent_string(v)?),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn percent_string<E: serde::ser::Error>(value: impl fmt::Display) -> Result<String, E> {
+    let percent = to_percent_text(value).map_err(E::custom)?;
+    Ok(format!("{}%", percent))
+}
+
+/// Serialize a value as its `Display` text in a string, such as `"12.345"`.
+///
+/// Keeps every digit of decimal and big-number types through formats whose numbers are `f64`.
+///
+/// # Examples
+///
+/// ```
+/// use serde::Serialize;
+/// use serde_tuplex::serialize_as_string;
+///
+/// #[derive(Serialize)]
+/// struct Order {
+///     #[serde(serialize_with = "serialize_as_string")]
+///     id: u64,
+/// }
+///
+/// let json = serde_json::to_string(&Order { id: 9_007_199_254_740_993 }).unwrap();
+/// assert_eq!(json, r#"{"id":"9007199254740993"}"#);
+/// ```
+pub fn serialize_as_string<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: fmt::Display,
+{
+    serializer.collect_str(value)
+}
+
+/// Serialize `Option<T>` as its `Display` text or `null`.
+pub fn serialize_as_string_option<S, T>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: fmt::Display,
+{
+    match value {
+        Some(v) => serializer.serialize_some(&AsText(v, TextRepr::String)),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Serialize a value whose `Display` text is a number, such as a decimal, as a native number.
+///
+/// Integers that fit 128 bits are written as integers. Other numbers keep their exact digits
+/// with the `arbitrary_precision` feature and go through `f64` otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use serde::Serialize;
+/// use serde_tuplex::serialize_as_number;
+///
+/// #[derive(Serialize)]
+/// struct Price {
+///     #[serde(serialize_with = "serialize_as_number")]
+///     amount: String,
+/// }
+///
+/// let json = serde_json::to_string(&Price { amount: "12.5".into() }).unwrap();
+/// assert_eq!(json, r#"{"amount":12.5}"#);
+/// ```
+pub fn serialize_as_number<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: fmt::Display,
+{
+    serialize_number_text(&value.to_string(), serializer)
+}
+
+/// Serialize `Option<T>` like [`serialize_as_number`], or `null`.
+pub fn serialize_as_number_option<S, T>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: fmt::Display,
+{
+    match value {
+        Some(v) => serializer.serialize_some(&AsText(v, TextRepr::Number)),
+        None => serializer.serialize_none(),
+    }
+}
@@ -0,0 +1,168 @@
+//! Timestamps from epoch numbers and RFC 3339 strings.
+
+use crate::duration::{DurationUnit, scaled_nanos};
+use crate::number::DecimalParts;
+
+#[cfg(feature = "chrono")]
+const NANOS_PER_SEC: i128 = 1_000_000_000;
+
+/// The unit of a bare epoch number read as a timestamp.
+///
+/// Selected with `#[serde_tuplex(timestamp = "ms")]`; the names are `"s"`, `"ms"`, `"us"`,
+/// `"ns"` and `"auto"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimestampUnit {
+    Secs,
+    Millis,
+    Micros,
+    Nanos,
+    /// Guess from the number of integer digits: up to 11 are seconds (until the year 5138),
+    /// up to 14 milliseconds, up to 17 microseconds, and longer ones nanoseconds.
+    Auto,
+}
+
+impl TimestampUnit {
+    /// The unit of a number with `digits` integer digits.
+    fn resolve(self, digits: usize) -> DurationUnit {
+        match self {
+            TimestampUnit::Secs => DurationUnit::Secs,
+            TimestampUnit::Millis => DurationUnit::Millis,
+            TimestampUnit::Micros => DurationUnit::Micros,
+            TimestampUnit::Nanos => DurationUnit::Nanos,
+            TimestampUnit::Auto => match digits {
+                0..=11 => DurationUnit::Secs,
+                12..=14 => DurationUnit::Millis,
+                15..=17 => DurationUnit::Micros,
+                _ => DurationUnit::Nanos,
+            },
+        }
+    }
+
+    /// The unit epoch numbers are written in; `Auto` writes seconds.
+    pub(crate) fn write_unit(self) -> DurationUnit {
+        self.resolve(0)
+    }
+}
+
+/// A date-time that can be read from an epoch offset or an RFC 3339 string.
+///
+/// Implemented for `chrono::DateTime<Utc>` with the `chrono` feature and for
+/// `time::OffsetDateTime` with the `time` feature.
+pub trait Timestamp: Sized {
+    /// The instant `nanos` nanoseconds after 1970-01-01T00:00:00Z.
+    fn from_epoch_nanos(nanos: i128) -> Result<Self, String>;
+
+    /// Parse `"2024-01-02T03:04:05Z"` or `"2024-01-02T03:04:05.5+01:00"`.
+    fn parse_rfc3339(s: &str) -> Result<Self, String>;
+
+    /// Nanoseconds since 1970-01-01T00:00:00Z.
+    fn epoch_nanos(&self) -> i128;
+}
+
+/// Parse an epoch number (in `unit`) or an RFC 3339 string.
+pub(crate) fn parse_timestamp_str<T: Timestamp>(s: &str, unit: TimestampUnit) -> Result<T, String> {
+    let trimmed = s.trim();
+    let Some(parts) = DecimalParts::parse(trimmed) else {
+        return T::parse_rfc3339(trimmed);
+    };
+    let too_large = || format!("timestamp {:?} is out of range", s);
+    let plain = parts.to_plain().ok_or_else(too_large)?;
+    let (negative, plain) = match plain.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, plain.as_str()),
+    };
+    let (int, frac) = plain.split_once('.').unwrap_or((plain, ""));
+    let unit = unit.resolve(int.trim_start_matches('0').len());
+    let nanos = scaled_nanos(int, frac, unit)
+        .and_then(|n| i128::try_from(n).ok())
+        .ok_or_else(too_large)?;
+    T::from_epoch_nanos(if negative { -nanos } else { nanos })
+}
+
+/// Split epoch nanoseconds into whole seconds and the nanoseconds after them.
+#[cfg(feature = "chrono")]
+fn split_nanos(nanos: i128) -> Option<(i64, u32)> {
+    let secs = i64::try_from(nanos.div_euclid(NANOS_PER_SEC)).ok()?;
+    Some((secs, nanos.rem_euclid(NANOS_PER_SEC) as u32))
+}
+
+/// Epoch offset of `timestamp` in `unit`, integral when exact.
+pub(crate) fn serialize_epoch<T, S>(
+    timestamp: &T,
+    unit: TimestampUnit,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    T: Timestamp,
+    S: serde::Serializer,
+{
+    let nanos = timestamp.epoch_nanos();
+    let per_unit = unit.write_unit().nanos() as i128;
+    if nanos % per_unit != 0 {
+        serializer.serialize_f64(nanos as f64 / per_unit as f64)
+    } else if let Ok(count) = i64::try_from(nanos / per_unit) {
+        serializer.serialize_i64(count)
+    } else {
+        serializer.serialize_i128(nanos / per_unit)
+    }
+}
+
+/// Bare numbers are epoch seconds; other strings are RFC 3339.
+#[cfg(any(feature = "chrono", feature = "time"))]
+macro_rules! impl_lenient_parse_timestamp {
+    ($ty:ty) => {
+        impl crate::parse::LenientParse for $ty {
+            const NORMALIZE: crate::normalize::Normalize = crate::normalize::Normalize::DEFAULT;
+
+            fn parse_bool(v: bool) -> Result<Self, String> {
+                Err(format!("{} is not a valid timestamp", v))
+            }
+
+            fn parse_str(s: &str) -> Result<Self, String> {
+                parse_timestamp_str(s, TimestampUnit::Secs)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "chrono")]
+impl Timestamp for chrono::DateTime<chrono::Utc> {
+    fn from_epoch_nanos(nanos: i128) -> Result<Self, String> {
+        split_nanos(nanos)
+            .and_then(|(secs, nanos)| chrono::DateTime::from_timestamp(secs, nanos))
+            .ok_or_else(|| format!("epoch offset of {}ns is out of range", nanos))
+    }
+
+    fn parse_rfc3339(s: &str) -> Result<Self, String> {
+        chrono::DateTime::parse_from_rfc3339(s)
+            .map(|t| t.to_utc())
+            .map_err(|e| format!("invalid timestamp {:?}: {}", s, e))
+    }
+
+    fn epoch_nanos(&self) -> i128 {
+        i128::from(self.timestamp()) * NANOS_PER_SEC + i128::from(self.timestamp_subsec_nanos())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl_lenient_parse_timestamp!(chrono::DateTime<chrono::Utc>);
+
+#[cfg(feature = "time")]
+impl Timestamp for time::OffsetDateTime {
+    fn from_epoch_nanos(nanos: i128) -> Result<Self, String> {
+        time::OffsetDateTime::from_unix_timestamp_nanos(nanos)
+            .map_err(|_| format!("epoch offset of {}ns is out of range", nanos))
+    }
+
+    fn parse_rfc3339(s: &str) -> Result<Self, String> {
+        time::OffsetDateTime::parse(s, &time::format_description::well_known::Rfc3339)
+            .map_err(|e| format!("invalid timestamp {:?}: {}", s, e))
+    }
+
+    fn epoch_nanos(&self) -> i128 {
+        self.unix_timestamp_nanos()
+    }
+}
+
+#[cfg(feature = "time")]
+impl_lenient_parse_timestamp!(time::OffsetDateTime);
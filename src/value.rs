@@ -0,0 +1,542 @@
+//! The "number or string" input captured before parsing.
+
+use crate::bytes::parse_byte_size;
+use crate::duration::{DurationUnit, duration_from_int, duration_from_number, parse_duration_str};
+use crate::format::NumberFormat;
+use crate::normalize::Normalize;
+use crate::parse::{LenientParse, RadixParse};
+use crate::percent::{PercentMode, parse_hundredths, parse_percent_str};
+use crate::timestamp::{Timestamp, TimestampUnit, parse_timestamp_str};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::borrow::Cow;
+use std::fmt;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+/// A value that was sent as either a number or a string.
+///
+/// This is the intake behind every lenient field: deserialize it from anything that produces
+/// a string, integer, float or boolean, inspect it, then [`parse`](Self::parse) it into the target
+/// type. Strings borrow from the input when the deserializer allows it. It is also the input
+/// handed to `#[serde_tuplex(parse_with = "...")]` parsers.
+///
+/// Use `Option<LenientValue>` for values that may be `null`.
+///
+/// Equality is structural: `LenientValue::String("1".into())` is not equal to
+/// `LenientValue::I64(1)`.
+///
+/// # Examples
+///
+/// ```
+/// use serde_tuplex::LenientValue;
+///
+/// let value: LenientValue = serde_json::from_str(r#""42""#).unwrap();
+/// assert!(value.is_string());
+/// assert_eq!(value.as_str(), Some("42"));
+/// assert_eq!(value.parse::<u64>(), Ok(42));
+///
+/// let value: LenientValue = serde_json::from_str("-7").unwrap();
+/// assert!(value.is_integer());
+/// assert_eq!(value.as_i64(), Some(-7));
+/// assert_eq!(value.to_string(), "-7");
+///
+/// let missing: Option<LenientValue> = serde_json::from_str("null").unwrap();
+/// assert!(missing.is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum LenientValue<'a> {
+    /// A string, e.g. `"42"`.
+    String(Cow<'a, str>),
+    /// A native number kept as its exact text, e.g. from serde_json's
+    /// `arbitrary_precision` mode.
+    Number(Cow<'a, str>),
+    I64(i64),
+    U64(u64),
+    I128(i128),
+    U128(u128),
+    F64(f64),
+    Bool(bool),
+}
+
+impl LenientValue<'_> {
+    /// Parse to target type via [`LenientParse`].
+    ///
+    /// Strings are first cleaned up with [`T::NORMALIZE`](LenientParse::NORMALIZE), so
+    /// `" +1_000 "` parses as a number.
+    pub fn parse<T: LenientParse>(&self) -> Result<T, String> {
+        self.normalized(T::NORMALIZE).parse_exact()
+    }
+
+    /// [`parse`](Self::parse) without the clean-up step.
+    #[doc(hidden)]
+    pub fn parse_exact<T: LenientParse>(&self) -> Result<T, String> {
+        match self {
+            LenientValue::String(s) => T::parse_str(s),
+            LenientValue::Number(s) => T::parse_number(s),
+            LenientValue::I64(v) => T::parse_i64(*v),
+            LenientValue::U64(v) => T::parse_u64(*v),
+            LenientValue::I128(v) => T::parse_i128(*v),
+            LenientValue::U128(v) => T::parse_u128(*v),
+            LenientValue::F64(v) => T::parse_f64(*v),
+            LenientValue::Bool(v) => T::parse_bool(*v),
+        }
+    }
+
+    /// Parse to an integer, also accepting `0x`/`0o`/`0b` prefixed strings.
+    ///
+    /// See [`RadixParse::parse_radix_str`]; native numbers convert as in [`parse`](Self::parse).
+    pub fn parse_radix<T: RadixParse>(&self) -> Result<T, String> {
+        self.normalized(T::NORMALIZE).parse_radix_exact()
+    }
+
+    /// [`parse_radix`](Self::parse_radix) without the clean-up step.
+    #[doc(hidden)]
+    pub fn parse_radix_exact<T: RadixParse>(&self) -> Result<T, String> {
+        match self {
+            LenientValue::String(s) => T::parse_radix_str(s),
+            other => other.parse_exact(),
+        }
+    }
+
+    /// Parse a byte size: numbers as bytes, or strings with an SI or IEC suffix such as
+    /// `"1MB"`, `"1 MiB"` or `"512k"`.
+    ///
+    /// Suffixes ignore case; `k`/`kB` mean 1000 and `Ki`/`KiB` mean 1024. Fractional byte
+    /// counts and values that do not fit `T` are errors.
+    ///
+    /// ```
+    /// use serde_tuplex::LenientValue;
+    ///
+    /// assert_eq!(LenientValue::String("1 MiB".into()).parse_bytes::<u64>(), Ok(1_048_576));
+    /// assert_eq!(LenientValue::String("1.5kB".into()).parse_bytes::<u64>(), Ok(1500));
+    /// assert!(LenientValue::String("1.5B".into()).parse_bytes::<u64>().is_err());
+    /// ```
+    pub fn parse_bytes<T: LenientParse>(&self) -> Result<T, String> {
+        self.normalized(T::NORMALIZE).parse_bytes_exact()
+    }
+
+    /// [`parse_bytes`](Self::parse_bytes) without the clean-up step.
+    #[doc(hidden)]
+    pub fn parse_bytes_exact<T: LenientParse>(&self) -> Result<T, String> {
+        match self {
+            LenientValue::String(s) => T::parse_u128(parse_byte_size(s)?),
+            other => other.parse_exact(),
+        }
+    }
+
+    /// Parse to a [`Duration`]: bare numbers count `unit`s, strings may also be suffixed
+    /// (`"1m30s"`, `"500ms"`, `"1.5h"`) or ISO-8601 (`"PT1M30S"`).
+    ///
+    /// Strings are cleaned up with [`Normalize::DEFAULT`] first.
+    ///
+    /// ```
+    /// use serde_tuplex::{DurationUnit, LenientValue};
+    /// use std::time::Duration;
+    ///
+    /// let value = LenientValue::String("1m30s".into());
+    /// assert_eq!(value.parse_duration(DurationUnit::Millis), Ok(Duration::from_secs(90)));
+    /// let value = LenientValue::U64(3000);
+    /// assert_eq!(value.parse_duration(DurationUnit::Millis), Ok(Duration::from_secs(3)));
+    /// ```
+    pub fn parse_duration(&self, unit: DurationUnit) -> Result<Duration, String> {
+        self.normalized(Duration::NORMALIZE)
+            .parse_duration_exact(unit)
+    }
+
+    /// [`parse_duration`](Self::parse_duration) without the clean-up step.
+    #[doc(hidden)]
+    pub fn parse_duration_exact(&self, unit: DurationUnit) -> Result<Duration, String> {
+        match self {
+            LenientValue::String(s) | LenientValue::Number(s) => parse_duration_str(s, unit),
+            LenientValue::I64(v) => duration_from_int(i128::from(*v), unit),
+            LenientValue::U64(v) => duration_from_int(i128::from(*v), unit),
+            LenientValue::I128(v) => duration_from_int(*v, unit),
+            LenientValue::U128(v) => parse_duration_str(&v.to_string(), unit),
+            LenientValue::F64(v) => duration_from_number(*v, unit),
+            LenientValue::Bool(v) => Err(format!("{} is not a valid duration", v)),
+        }
+    }
+
+    /// Parse a percentage: `"12.5%"` is read as `0.125`, and bare numbers as ratios or
+    /// percentages according to `mode`.
+    ///
+    /// The decimal point is moved in the text, so `"33.3%"` gives exactly `0.333`.
+    ///
+    /// ```
+    /// use serde_tuplex::{LenientValue, PercentMode};
+    ///
+    /// let value = LenientValue::String("12.5%".into());
+    /// assert_eq!(value.parse_percent::<f64>(PercentMode::Ratio), Ok(0.125));
+    /// assert_eq!(LenientValue::F64(0.5).parse_percent::<f64>(PercentMode::Ratio), Ok(0.5));
+    /// assert_eq!(LenientValue::U64(50).parse_percent::<f64>(PercentMode::Percent), Ok(0.5));
+    /// ```
+    pub fn parse_percent<T: LenientParse>(&self, mode: PercentMode) -> Result<T, String> {
+        self.normalized(T::NORMALIZE).parse_percent_exact(mode)
+    }
+
+    /// [`parse_percent`](Self::parse_percent) without the clean-up step.
+    #[doc(hidden)]
+    pub fn parse_percent_exact<T: LenientParse>(&self, mode: PercentMode) -> Result<T, String> {
+        match (self, mode) {
+            (LenientValue::String(s), _) => parse_percent_str(s, mode),
+            (other, PercentMode::Ratio) | (other @ LenientValue::Bool(_), _) => other.parse_exact(),
+            (LenientValue::F64(v), PercentMode::Percent) if !v.is_finite() => T::parse_f64(*v),
+            // `arbitrary_precision` text such as `1e2` keeps its exponent, which the decimal
+            // point shift expands.
+            (LenientValue::Number(s), PercentMode::Percent) => parse_hundredths(s),
+            // `Display` for the remaining number variants never uses an exponent.
+            (other, PercentMode::Percent) => parse_hundredths(&other.to_string()),
+        }
+    }
+
+    /// Parse a timestamp from an epoch number in `unit` or an RFC 3339 string such as
+    /// `"2024-01-02T03:04:05Z"`.
+    ///
+    /// Numeric strings count as epoch numbers. Strings are cleaned up with
+    /// [`Normalize::DEFAULT`] first.
+    pub fn parse_timestamp<T: Timestamp>(&self, unit: TimestampUnit) -> Result<T, String> {
+        self.normalized(Normalize::DEFAULT)
+            .parse_timestamp_exact(unit)
+    }
+
+    /// [`parse_timestamp`](Self::parse_timestamp) without the clean-up step.
+    #[doc(hidden)]
+    pub fn parse_timestamp_exact<T: Timestamp>(&self, unit: TimestampUnit) -> Result<T, String> {
+        match self {
+            LenientValue::String(s) | LenientValue::Number(s) => parse_timestamp_str(s, unit),
+            LenientValue::Bool(v) => Err(format!("{} is not a valid timestamp", v)),
+            other => parse_timestamp_str(&other.to_string(), unit),
+        }
+    }
+
+    /// Apply the clean-up `steps` to a string; numbers pass through.
+    ///
+    /// See [`Normalize::apply`].
+    pub fn normalized(&self, steps: Normalize) -> LenientValue<'_> {
+        match self {
+            LenientValue::String(s) => LenientValue::String(steps.apply(s)),
+            LenientValue::Number(s) => LenientValue::Number(Cow::Borrowed(s)),
+            other => other.clone(),
+        }
+    }
+
+    /// Rewrite a string written in `format` into plain `1234.56` form; numbers pass through.
+    ///
+    /// See [`NumberFormat::normalize`].
+    pub fn normalize_with(&self, format: &NumberFormat) -> Result<LenientValue<'_>, String> {
+        Ok(match self {
+            LenientValue::String(s) => LenientValue::String(format.normalize(s)?),
+            LenientValue::Number(s) => LenientValue::Number(Cow::Borrowed(s)),
+            other => other.clone(),
+        })
+    }
+
+    /// Detach from the input buffer, copying a borrowed string.
+    pub fn into_owned(self) -> LenientValue<'static> {
+        match self {
+            LenientValue::String(s) => LenientValue::String(Cow::Owned(s.into_owned())),
+            LenientValue::Number(s) => LenientValue::Number(Cow::Owned(s.into_owned())),
+            LenientValue::I64(v) => LenientValue::I64(v),
+            LenientValue::U64(v) => LenientValue::U64(v),
+            LenientValue::I128(v) => LenientValue::I128(v),
+            LenientValue::U128(v) => LenientValue::U128(v),
+            LenientValue::F64(v) => LenientValue::F64(v),
+            LenientValue::Bool(v) => LenientValue::Bool(v),
+        }
+    }
+
+    /// Whether the value was sent as a string.
+    pub fn is_string(&self) -> bool {
+        matches!(self, LenientValue::String(_))
+    }
+
+    /// Whether the value was sent as a native integer.
+    pub fn is_integer(&self) -> bool {
+        match self {
+            LenientValue::Number(s) => !is_float_text(s),
+            LenientValue::I64(_)
+            | LenientValue::U64(_)
+            | LenientValue::I128(_)
+            | LenientValue::U128(_) => true,
+            LenientValue::String(_) | LenientValue::F64(_) | LenientValue::Bool(_) => false,
+        }
+    }
+
+    /// Whether the value was sent as a native float.
+    pub fn is_float(&self) -> bool {
+        match self {
+            LenientValue::Number(s) => is_float_text(s),
+            LenientValue::F64(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Whether the value was sent as a native boolean.
+    pub fn is_bool(&self) -> bool {
+        matches!(self, LenientValue::Bool(_))
+    }
+
+    /// The string, if the value was sent as one.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            LenientValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// The native integer, if it was sent as one that fits `i64`.
+    pub fn as_i64(&self) -> Option<i64> {
+        self.as_i128().and_then(|v| i64::try_from(v).ok())
+    }
+
+    /// The native integer, if it was sent as one that fits `u64`.
+    pub fn as_u64(&self) -> Option<u64> {
+        self.as_u128().and_then(|v| u64::try_from(v).ok())
+    }
+
+    /// The native integer, if it was sent as one that fits `i128`.
+    pub fn as_i128(&self) -> Option<i128> {
+        match self {
+            LenientValue::Number(s) if !is_float_text(s) => s.parse().ok(),
+            LenientValue::I64(v) => Some(i128::from(*v)),
+            LenientValue::U64(v) => Some(i128::from(*v)),
+            LenientValue::I128(v) => Some(*v),
+            LenientValue::U128(v) => i128::try_from(*v).ok(),
+            _ => None,
+        }
+    }
+
+    /// The native integer, if it was sent as one that fits `u128`.
+    pub fn as_u128(&self) -> Option<u128> {
+        match self {
+            LenientValue::Number(s) if !is_float_text(s) => s.parse().ok(),
+            LenientValue::I64(v) => u128::try_from(*v).ok(),
+            LenientValue::U64(v) => Some(u128::from(*v)),
+            LenientValue::I128(v) => u128::try_from(*v).ok(),
+            LenientValue::U128(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Any native number as `f64`, rounding integers that `f64` cannot hold exactly.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            LenientValue::Number(s) => s.parse().ok(),
+            LenientValue::I64(v) => Some(*v as f64),
+            LenientValue::U64(v) => Some(*v as f64),
+            LenientValue::I128(v) => Some(*v as f64),
+            LenientValue::U128(v) => Some(*v as f64),
+            LenientValue::F64(v) => Some(*v),
+            LenientValue::String(_) | LenientValue::Bool(_) => None,
+        }
+    }
+
+    /// The native boolean, if it was sent as one.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            LenientValue::Bool(v) => Some(*v),
+            _ => None,
+        }
+    }
+}
+
+/// Whether exact number text is written as a float (`1.5`, `1e3`).
+fn is_float_text(s: &str) -> bool {
+    s.contains(['.', 'e', 'E'])
+}
+
+impl fmt::Display for LenientValue<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LenientValue::String(s) | LenientValue::Number(s) => f.write_str(s),
+            LenientValue::I64(v) => v.fmt(f),
+            LenientValue::U64(v) => v.fmt(f),
+            LenientValue::I128(v) => v.fmt(f),
+            LenientValue::U128(v) => v.fmt(f),
+            LenientValue::F64(v) => v.fmt(f),
+            LenientValue::Bool(v) => v.fmt(f),
+        }
+    }
+}
+
+impl Serialize for LenientValue<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            LenientValue::String(s) => serializer.serialize_str(s),
+            LenientValue::Number(s) => serialize_number_text(s, serializer),
+            LenientValue::I64(v) => serializer.serialize_i64(*v),
+            LenientValue::U64(v) => serializer.serialize_u64(*v),
+            LenientValue::I128(v) => serializer.serialize_i128(*v),
+            LenientValue::U128(v) => serializer.serialize_u128(*v),
+            LenientValue::F64(v) => serializer.serialize_f64(*v),
+            LenientValue::Bool(v) => serializer.serialize_bool(*v),
+        }
+    }
+}
+
+/// Map key serde_json uses for numbers under `arbitrary_precision`.
+#[cfg(feature = "arbitrary_precision")]
+const SERDE_JSON_NUMBER_TOKEN: &str = "$serde_json::private::Number";
+
+/// Serialize exact number text as a native number.
+///
+/// Integers use the narrowest fitting integer type. Other text is written verbatim to serde_json
+/// under `arbitrary_precision`; everything else gets the nearest `f64`, except that under
+/// `arbitrary_precision` text the `f64` cannot hold exactly is written as a string.
+pub(crate) fn serialize_number_text<S: Serializer>(
+    s: &str,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    if let Ok(v) = s.parse::<u64>() {
+        return serializer.serialize_u64(v);
+    }
+    if let Ok(v) = s.parse::<i64>() {
+        return serializer.serialize_i64(v);
+    }
+    if let Ok(v) = s.parse::<u128>() {
+        return serializer.serialize_u128(v);
+    }
+    if let Ok(v) = s.parse::<i128>() {
+        return serializer.serialize_i128(v);
+    }
+    #[cfg(feature = "arbitrary_precision")]
+    {
+        // Only serde_json understands this struct, and writes it as the raw number text.
+        if std::any::type_name::<S>().contains("serde_json::") {
+            use serde::ser::SerializeStruct;
+            let mut state = serializer.serialize_struct(SERDE_JSON_NUMBER_TOKEN, 1)?;
+            state.serialize_field(SERDE_JSON_NUMBER_TOKEN, s)?;
+            return state.end();
+        }
+        if !f64_holds_exactly(s) {
+            return serializer.serialize_str(s);
+        }
+    }
+    let v: f64 = s.parse().map_err(serde::ser::Error::custom)?;
+    serializer.serialize_f64(v)
+}
+
+/// Whether the nearest `f64` to decimal text `s` has the same value.
+#[cfg(feature = "arbitrary_precision")]
+fn f64_holds_exactly(s: &str) -> bool {
+    let plain = |text: &str| {
+        let plain = crate::number::DecimalParts::parse(text)?.to_plain()?;
+        if plain.contains('.') {
+            Some(
+                plain
+                    .trim_end_matches('0')
+                    .trim_end_matches('.')
+                    .to_string(),
+            )
+        } else {
+            Some(plain)
+        }
+    };
+    match s.parse::<f64>() {
+        Ok(v) if v.is_finite() => plain(s).is_some_and(|p| plain(&format!("{:e}", v)) == Some(p)),
+        _ => false,
+    }
+}
+
+impl<'de: 'a, 'a> Deserialize<'de> for LenientValue<'a> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct LenientValueVisitor<'a>(PhantomData<LenientValue<'a>>);
+
+        impl<'de: 'a, 'a> serde::de::Visitor<'de> for LenientValueVisitor<'a> {
+            type Value = LenientValue<'a>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a number, string or boolean")
+            }
+
+            fn visit_bool<E>(self, value: bool) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(LenientValue::Bool(value))
+            }
+
+            fn visit_borrowed_str<E>(self, value: &'de str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(LenientValue::String(Cow::Borrowed(value)))
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(LenientValue::String(Cow::Owned(value.to_string())))
+            }
+
+            fn visit_string<E>(self, value: String) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(LenientValue::String(Cow::Owned(value)))
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(LenientValue::I64(value))
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(LenientValue::U64(value))
+            }
+
+            fn visit_i128<E>(self, value: i128) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(LenientValue::I128(value))
+            }
+
+            fn visit_u128<E>(self, value: u128) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(LenientValue::U128(value))
+            }
+
+            fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(LenientValue::F64(value))
+            }
+
+            /// serde_json's `arbitrary_precision` numbers arrive as `{TOKEN: "digits"}`.
+            #[cfg(feature = "arbitrary_precision")]
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                match map.next_key::<&str>()? {
+                    Some(SERDE_JSON_NUMBER_TOKEN) => {
+                        Ok(LenientValue::Number(Cow::Owned(map.next_value()?)))
+                    }
+                    _ => Err(serde::de::Error::invalid_type(
+                        serde::de::Unexpected::Map,
+                        &self,
+                    )),
+                }
+            }
+        }
+
+        deserializer.deserialize_any(LenientValueVisitor(PhantomData))
+    }
+}
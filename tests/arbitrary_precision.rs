@@ -0,0 +1,135 @@
+#![cfg(feature = "arbitrary_precision")]
+
+use serde::Deserialize;
+use serde_tuplex::{
+    Lenient, LenientNumber, LenientParse, LenientValue, NumberSource, TupleLenient,
+};
+
+/// Keeps the exact decimal text it was given.
+#[derive(Debug, PartialEq)]
+struct Amount(String);
+
+impl LenientParse for Amount {
+    fn parse_str(s: &str) -> Result<Self, String> {
+        Ok(Amount(s.to_string()))
+    }
+}
+
+#[derive(Debug, Lenient, PartialEq)]
+struct Balance {
+    big: u128,
+    signed: i128,
+    #[serde_tuplex(lenient)]
+    amount: Amount,
+    ratio: f64,
+}
+
+#[derive(Debug, TupleLenient, PartialEq)]
+struct Row {
+    big: u128,
+    small: Option<u8>,
+}
+
+#[test]
+fn test_lenient_value_keeps_digits() {
+    let value: LenientValue = serde_json::from_str("123456789012345678901234567890").unwrap();
+    assert!(matches!(&value, LenientValue::Number(s) if s == "123456789012345678901234567890"));
+    assert_eq!(
+        value.parse::<u128>().unwrap(),
+        123_456_789_012_345_678_901_234_567_890
+    );
+
+    let value: LenientValue = serde_json::from_str("12.500").unwrap();
+    assert!(matches!(&value, LenientValue::Number(s) if s == "12.500"));
+}
+
+#[test]
+fn test_small_integers_stay_native() {
+    let value: LenientValue = serde_json::from_str("42").unwrap();
+    assert!(matches!(value, LenientValue::U64(42)));
+}
+
+#[test]
+fn test_lenient_derive_lossless() {
+    let json = r#"{
+        "big": 340282366920938463463374607431768211455,
+        "signed": -170141183460469231731687303715884105728,
+        "amount": 1234567890.123456789012345678,
+        "ratio": 0.1
+    }"#;
+    let balance: Balance = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        balance,
+        Balance {
+            big: u128::MAX,
+            signed: i128::MIN,
+            amount: Amount("1234567890.123456789012345678".to_string()),
+            ratio: 0.1,
+        }
+    );
+}
+
+#[test]
+fn test_tuple_lenient_lossless() {
+    let row: Row = serde_json::from_str("[18446744073709551616, 7.0]").unwrap();
+    assert_eq!(
+        row,
+        Row {
+            big: 1 << 64,
+            small: Some(7),
+        }
+    );
+
+    assert!(serde_json::from_str::<Row>("[1, 7.5]").is_err());
+}
+
+#[test]
+fn test_lenient_function_lossless() {
+    #[derive(Deserialize)]
+    struct Wrapper {
+        #[serde(deserialize_with = "serde_tuplex::lenient")]
+        value: i128,
+    }
+
+    let wrapper: Wrapper = serde_json::from_str(r#"{"value": -99999999999999999999999}"#).unwrap();
+    assert_eq!(wrapper.value, -99_999_999_999_999_999_999_999);
+}
+
+#[test]
+fn test_rejects_other_maps() {
+    let result: Result<LenientValue, _> = serde_json::from_str(r#"{"a": 1}"#);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_lenient_number_keeps_native_digits() {
+    let n: LenientNumber = serde_json::from_str("1.50").unwrap();
+    assert_eq!(n.source(), NumberSource::Native);
+    assert_eq!(n.raw(), "1.50");
+    assert_eq!(serde_json::to_string(&n).unwrap(), "1.50");
+
+    let n: LenientNumber = serde_json::from_str("340282366920938463463374607431768211455").unwrap();
+    assert_eq!(n.to_u128(), Some(u128::MAX));
+    assert_eq!(
+        serde_json::to_string(&n).unwrap(),
+        "340282366920938463463374607431768211455"
+    );
+}
+
+#[test]
+fn test_number_text_in_other_formats() {
+    use serde_test::{Token, assert_ser_tokens};
+
+    assert_ser_tokens(&LenientValue::Number("1.50".into()), &[Token::F64(1.5)]);
+    assert_ser_tokens(
+        &LenientValue::Number("-2.5e-1".into()),
+        &[Token::F64(-0.25)],
+    );
+    let long = "0.1000000000000000000001";
+    assert_ser_tokens(&LenientValue::Number(long.into()), &[Token::Str(long)]);
+    let huge: LenientNumber = serde_json::from_str("1e400").unwrap();
+    assert_ser_tokens(&huge, &[Token::Str("1e+400")]);
+
+    let n: LenientNumber = serde_json::from_str(long).unwrap();
+    assert_eq!(serde_json::to_value(&n).unwrap().to_string(), long);
+}
@@ -0,0 +1,139 @@
+#![cfg(any(feature = "num-bigint", feature = "primitive-types"))]
+
+#[cfg(feature = "num-bigint")]
+mod num_bigint_fields {
+    use num_bigint::{BigInt, BigUint};
+    use serde_tuplex::{Lenient, LenientValue, TupleLenient};
+
+    #[derive(Debug, Lenient, PartialEq)]
+    struct Balance {
+        amount: num_bigint::BigUint,
+        #[serde_tuplex(lenient)]
+        delta: Option<BigInt>,
+    }
+
+    #[derive(Debug, TupleLenient, PartialEq)]
+    struct Row {
+        amount: num_bigint::BigUint,
+        #[serde_tuplex(lenient)]
+        delta: Option<BigInt>,
+        #[serde_tuplex(lenient, serialize = "hex")]
+        mask: BigUint,
+    }
+
+    fn big(s: &str) -> BigUint {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_bigint_forms() {
+        let json = r#"{"amount": 42, "delta": "-340282366920938463463374607431768211456"}"#;
+        let balance: Balance = serde_json::from_str(json).unwrap();
+        assert_eq!(balance.amount, big("42"));
+        assert_eq!(
+            balance.delta,
+            Some(-BigInt::from(big(
+                "340282366920938463463374607431768211456"
+            )))
+        );
+
+        let json = r#"{"amount": "0xffffffffffffffffffffffffffffffffff", "delta": null}"#;
+        let balance: Balance = serde_json::from_str(json).unwrap();
+        assert_eq!(balance.amount, (BigUint::from(1u8) << 136u32) - 1u8);
+        assert_eq!(balance.delta, None);
+
+        let json = r#"{"amount": "1e30", "delta": 1.5e20}"#;
+        let balance: Balance = serde_json::from_str(json).unwrap();
+        assert_eq!(balance.amount, big("1000000000000000000000000000000"));
+        assert_eq!(
+            balance.delta,
+            Some(BigInt::from(150_000_000_000_000_000_000u128))
+        );
+
+        let err = serde_json::from_str::<Balance>(r#"{"amount": -1}"#).unwrap_err();
+        assert!(err.to_string().contains("out of range"));
+        assert!(serde_json::from_str::<Balance>(r#"{"amount": "1.5"}"#).is_err());
+        assert!(serde_json::from_str::<Balance>(r#"{"amount": "abc"}"#).is_err());
+        assert!(serde_json::from_str::<Balance>(r#"{"amount": true}"#).is_err());
+        assert_eq!(
+            LenientValue::String("-0".into()).parse::<BigUint>(),
+            Ok(big("0"))
+        );
+    }
+
+    #[test]
+    fn test_bigint_serialize() {
+        let row = Row {
+            amount: big("123456789012345678901234567890"),
+            delta: Some(BigInt::from(-5)),
+            mask: big("255"),
+        };
+        let json = serde_json::to_string(&row).unwrap();
+        assert_eq!(json, r#"["123456789012345678901234567890","-5","0xff"]"#);
+        assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), row);
+
+        let row = Row {
+            amount: big("0"),
+            delta: None,
+            mask: big("0"),
+        };
+        let json = serde_json::to_string(&row).unwrap();
+        assert_eq!(json, r#"["0",null,"0x0"]"#);
+        assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), row);
+    }
+}
+
+#[cfg(feature = "primitive-types")]
+mod primitive_types_fields {
+    use primitive_types::U256;
+    use serde_tuplex::TupleLenient;
+
+    #[derive(Debug, TupleLenient, PartialEq)]
+    struct Transfer {
+        value: primitive_types::U256,
+        #[serde_tuplex(lenient)]
+        fee: Option<U256>,
+    }
+
+    #[test]
+    fn test_u256_fields() {
+        let json = r#"["0xde0b6b3a7640000", 21000]"#;
+        let transfer: Transfer = serde_json::from_str(json).unwrap();
+        assert_eq!(transfer.value, U256::exp10(18));
+        assert_eq!(transfer.fee, Some(U256::from(21000)));
+        assert_eq!(
+            serde_json::to_string(&transfer).unwrap(),
+            r#"["1000000000000000000","21000"]"#
+        );
+
+        let max = U256::MAX.to_string();
+        let json = format!(r#"["{}", null]"#, max);
+        let transfer: Transfer = serde_json::from_str(&json).unwrap();
+        assert_eq!(transfer.value, U256::MAX);
+
+        let overflow = format!(r#"["{}0", null]"#, max);
+        let err = serde_json::from_str::<Transfer>(&overflow).unwrap_err();
+        assert!(err.to_string().contains("out of range"));
+        assert!(serde_json::from_str::<Transfer>(r#"["-1", null]"#).is_err());
+    }
+}
+
+#[test]
+fn test_other_crates_big_integer_names_stay_strict() {
+    mod other {
+        /// Stand-in for another crate's `U256`, which has no `LenientParse` impl.
+        #[derive(Debug, serde::Deserialize, PartialEq)]
+        pub struct U256(pub u64);
+    }
+    use other::U256;
+
+    #[derive(Debug, serde_tuplex::Lenient, PartialEq)]
+    struct Account {
+        balance: U256,
+        other: other::U256,
+    }
+
+    let account: Account = serde_json::from_str(r#"{"balance": 5, "other": 6}"#).unwrap();
+    assert_eq!(account.balance, U256(5));
+    assert!(serde_json::from_str::<Account>(r#"{"balance": "5", "other": 6}"#).is_err());
+}
@@ -0,0 +1,142 @@
+use serde::{Deserialize, Serialize};
+use serde_tuplex::{
+    Lenient, LenientValue, TupleLenient, lenient_bytes, lenient_bytes_option, serialize_bytes_si,
+};
+
+#[derive(Debug, Lenient, PartialEq)]
+struct Limits {
+    #[serde_tuplex(bytes)]
+    memory: u64,
+    #[serde_tuplex(bytes)]
+    quota: Option<u32>,
+}
+
+#[derive(Debug, TupleLenient, PartialEq)]
+#[serde_tuplex(radix_prefix)]
+struct Row {
+    #[serde_tuplex(bytes, serialize = "human")]
+    iec: u64,
+    #[serde_tuplex(bytes, serialize = "si")]
+    si: Option<u64>,
+    #[serde_tuplex(bytes)]
+    plain: u64,
+}
+
+fn memory(json: &str) -> Result<u64, String> {
+    serde_json::from_str::<Limits>(json)
+        .map(|l| l.memory)
+        .map_err(|e| e.to_string())
+}
+
+#[test]
+fn test_bytes_suffixes() {
+    assert_eq!(memory(r#"{"memory": 1048576}"#), Ok(1_048_576));
+    assert_eq!(memory(r#"{"memory": "1048576"}"#), Ok(1_048_576));
+    assert_eq!(memory(r#"{"memory": "1MB"}"#), Ok(1_000_000));
+    assert_eq!(memory(r#"{"memory": "1 MiB"}"#), Ok(1_048_576));
+    assert_eq!(memory(r#"{"memory": "512k"}"#), Ok(512_000));
+    assert_eq!(memory(r#"{"memory": "512ki"}"#), Ok(524_288));
+    assert_eq!(memory(r#"{"memory": "2gib"}"#), Ok(2 << 30));
+    assert_eq!(memory(r#"{"memory": "1.5KB"}"#), Ok(1500));
+    assert_eq!(memory(r#"{"memory": "0.5KiB"}"#), Ok(512));
+    assert_eq!(memory(r#"{"memory": "10b"}"#), Ok(10));
+    assert!(
+        memory(r#"{"memory": "16EiB"}"#)
+            .unwrap_err()
+            .contains("out of range")
+    );
+}
+
+#[test]
+fn test_bytes_errors() {
+    assert!(
+        memory(r#"{"memory": "1.5B"}"#)
+            .unwrap_err()
+            .contains("fractional bytes")
+    );
+    assert!(
+        memory(r#"{"memory": "1.0001KB"}"#)
+            .unwrap_err()
+            .contains("fractional bytes")
+    );
+    assert!(
+        memory(r#"{"memory": 1.5}"#)
+            .unwrap_err()
+            .contains("not an integer")
+    );
+    assert!(
+        memory(r#"{"memory": "1XB"}"#)
+            .unwrap_err()
+            .contains("unknown unit")
+    );
+    assert!(
+        memory(r#"{"memory": "MB"}"#)
+            .unwrap_err()
+            .contains("expected a number")
+    );
+    assert!(memory(r#"{"memory": "-1MB"}"#).is_err());
+    assert!(memory(r#"{"memory": -1}"#).is_err());
+
+    let json = r#"{"memory": 1, "quota": "5GB"}"#;
+    let err = serde_json::from_str::<Limits>(json).unwrap_err();
+    assert!(err.to_string().contains("out of range for u32"));
+}
+
+#[test]
+fn test_bytes_option() {
+    let json = r#"{"memory": "1k", "quota": "64 KiB"}"#;
+    let limits: Limits = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        limits,
+        Limits {
+            memory: 1000,
+            quota: Some(65_536),
+        }
+    );
+    let limits: Limits = serde_json::from_str(r#"{"memory": 1, "quota": null}"#).unwrap();
+    assert_eq!(limits.quota, None);
+}
+
+#[test]
+fn test_bytes_serialize() {
+    let row = Row {
+        iec: 1536,
+        si: Some(2_500_000),
+        plain: 1025,
+    };
+    let json = serde_json::to_string(&row).unwrap();
+    assert_eq!(json, r#"["1.5KiB","2.5MB",1025]"#);
+    assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), row);
+
+    let row = Row {
+        iec: 1025,
+        si: None,
+        plain: 0,
+    };
+    let json = serde_json::to_string(&row).unwrap();
+    assert_eq!(json, r#"["1025B",null,0]"#);
+    assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), row);
+}
+
+#[test]
+fn test_bytes_functions() {
+    #[derive(Debug, Deserialize, Serialize, PartialEq)]
+    struct Plain {
+        #[serde(
+            deserialize_with = "lenient_bytes",
+            serialize_with = "serialize_bytes_si"
+        )]
+        disk: u64,
+        #[serde(deserialize_with = "lenient_bytes_option", default)]
+        swap: Option<u64>,
+    }
+
+    let plain: Plain = serde_json::from_str(r#"{"disk": "20GB", "swap": "1 GiB"}"#).unwrap();
+    assert_eq!(plain.disk, 20_000_000_000);
+    assert_eq!(plain.swap, Some(1 << 30));
+    let json = serde_json::to_string(&plain).unwrap();
+    assert_eq!(json, r#"{"disk":"20GB","swap":1073741824}"#);
+
+    let value = LenientValue::String("1_000 kB".into());
+    assert_eq!(value.parse_bytes::<u32>(), Ok(1_000_000));
+}
@@ -0,0 +1,153 @@
+use serde::Serialize;
+use serde_tuplex::{serialize_as_number, serialize_as_string_option};
+
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal_fields {
+    use rust_decimal::Decimal;
+    use serde_tuplex::{Lenient, LenientValue, TupleLenient};
+    use std::str::FromStr;
+
+    #[derive(Debug, Lenient, PartialEq)]
+    struct Order {
+        total: rust_decimal::Decimal,
+        discount: Option<rust_decimal::Decimal>,
+        #[serde_tuplex(lenient, number_format = "de")]
+        local: Decimal,
+    }
+
+    #[derive(Debug, TupleLenient, PartialEq)]
+    struct Row {
+        #[serde_tuplex(lenient)]
+        price: Decimal,
+        #[serde_tuplex(serialize = "number")]
+        tax: Option<rust_decimal::Decimal>,
+    }
+
+    fn dec(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_decimal_exact_digits() {
+        let json = r#"{"total": 12.345, "discount": "0.10", "local": "1.234,50"}"#;
+        let order: Order = serde_json::from_str(json).unwrap();
+        assert_eq!(order.total.to_string(), "12.345");
+        assert_eq!(order.discount.unwrap().to_string(), "0.10");
+        assert_eq!(order.local, dec("1234.5"));
+
+        let json = r#"{"total": "1.5e3", "discount": null, "local": 0}"#;
+        let order: Order = serde_json::from_str(json).unwrap();
+        assert_eq!(order.total, dec("1500"));
+        assert_eq!(order.discount, None);
+
+        let json = r#"{"total": 0.1, "local": "１２"}"#;
+        let order: Order = serde_json::from_str(json).unwrap();
+        assert_eq!(order.total.to_string(), "0.1");
+        assert_eq!(order.local, dec("12"));
+
+        let err = serde_json::from_str::<Order>(r#"{"total": "abc", "local": 0}"#).unwrap_err();
+        assert!(err.to_string().contains("invalid decimal"));
+        assert!(serde_json::from_str::<Order>(r#"{"total": true, "local": 0}"#).is_err());
+    }
+
+    #[test]
+    fn test_decimal_serialize() {
+        let row = Row {
+            price: dec("12.345"),
+            tax: Some(dec("0.5")),
+        };
+        let json = serde_json::to_string(&row).unwrap();
+        assert_eq!(json, r#"["12.345",0.5]"#);
+        assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), row);
+
+        let row = Row {
+            price: dec("100"),
+            tax: None,
+        };
+        let json = serde_json::to_string(&row).unwrap();
+        assert_eq!(json, r#"["100",null]"#);
+        assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), row);
+
+        let value = LenientValue::Number("3.14159265358979323846".into());
+        assert_eq!(value.parse::<Decimal>(), Ok(dec("3.14159265358979323846")));
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal_fields {
+    use bigdecimal::BigDecimal;
+    use serde_tuplex::{Lenient, TupleLenient};
+    use std::str::FromStr;
+
+    #[derive(Debug, Lenient, PartialEq)]
+    struct Reading {
+        value: bigdecimal::BigDecimal,
+        #[serde_tuplex(lenient, normalize = "none")]
+        raw: Option<BigDecimal>,
+    }
+
+    #[test]
+    fn test_bigdecimal_fields() {
+        let json = r#"{"value": 12.345, "raw": "1e-30"}"#;
+        let reading: Reading = serde_json::from_str(json).unwrap();
+        assert_eq!(reading.value, BigDecimal::from_str("12.345").unwrap());
+        assert_eq!(reading.raw, Some(BigDecimal::from_str("1e-30").unwrap()));
+
+        let json = r#"{"value": "123456789012345678901234567890.5"}"#;
+        let reading: Reading = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            reading.value.to_string(),
+            "123456789012345678901234567890.5"
+        );
+
+        assert!(serde_json::from_str::<Reading>(r#"{"value": 1, "raw": " 1"}"#).is_err());
+    }
+
+    #[test]
+    fn test_bigdecimal_serialize_default() {
+        #[derive(Debug, TupleLenient, PartialEq)]
+        struct Row {
+            value: bigdecimal::BigDecimal,
+            raw: Option<bigdecimal::BigDecimal>,
+        }
+
+        let row: Row = serde_json::from_str(r#"[0.25, "1e-30"]"#).unwrap();
+        let json = serde_json::to_string(&row).unwrap();
+        assert_eq!(json, r#"["0.25","1E-30"]"#);
+        assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), row);
+    }
+}
+
+#[test]
+fn test_serialize_as_functions() {
+    #[derive(Serialize)]
+    struct Plain {
+        #[serde(serialize_with = "serialize_as_number")]
+        amount: String,
+        #[serde(serialize_with = "serialize_as_string_option")]
+        count: Option<u64>,
+    }
+
+    let plain = Plain {
+        amount: "-7".into(),
+        count: Some(3),
+    };
+    let json = serde_json::to_string(&plain).unwrap();
+    assert_eq!(json, r#"{"amount":-7,"count":"3"}"#);
+}
+
+#[test]
+fn test_other_decimal_types_stay_strict() {
+    /// A user type that happens to share the name.
+    #[derive(Debug, serde::Deserialize, PartialEq)]
+    struct Decimal(f64);
+
+    #[derive(Debug, serde_tuplex::Lenient, PartialEq)]
+    struct Price {
+        amount: Decimal,
+    }
+
+    let price: Price = serde_json::from_str(r#"{"amount": 1.5}"#).unwrap();
+    assert_eq!(price.amount, Decimal(1.5));
+    assert!(serde_json::from_str::<Price>(r#"{"amount": "1.5"}"#).is_err());
+}
@@ -0,0 +1,147 @@
+use serde::{Deserialize, Serialize};
+use serde_tuplex::{
+    DurationUnit, Lenient, LenientValue, TupleLenient, lenient_duration, lenient_duration_option,
+    serialize_duration_iso8601,
+};
+use std::time::Duration;
+
+#[derive(Debug, Lenient, PartialEq)]
+struct Config {
+    #[serde_tuplex(duration = "ms")]
+    timeout: Duration,
+    #[serde_tuplex(duration = "s")]
+    retry: Option<core::time::Duration>,
+    #[serde_tuplex(duration = "m")]
+    window: std::time::Duration,
+}
+
+#[derive(Debug, TupleLenient, PartialEq)]
+struct Row {
+    #[serde_tuplex(duration = "ms")]
+    millis: Duration,
+    #[serde_tuplex(duration = "s", serialize = "human")]
+    human: Duration,
+    #[serde_tuplex(duration = "s", serialize = "iso8601")]
+    iso: Option<Duration>,
+}
+
+#[test]
+fn test_duration_forms() {
+    let ms = Duration::from_millis;
+    let cases = [
+        (r#"{"timeout": 3000, "window": 1}"#, ms(3000)),
+        (r#"{"timeout": "3000", "window": 1}"#, ms(3000)),
+        (
+            r#"{"timeout": 2.5, "window": 1}"#,
+            Duration::from_micros(2500),
+        ),
+        (r#"{"timeout": "3s", "window": 1}"#, ms(3000)),
+        (r#"{"timeout": "1m30s", "window": 1}"#, ms(90_000)),
+        (r#"{"timeout": "1h 30m", "window": 1}"#, ms(5_400_000)),
+        (r#"{"timeout": "1.5h", "window": 1}"#, ms(5_400_000)),
+        (r#"{"timeout": " 250ms ", "window": 1}"#, ms(250)),
+        (r#"{"timeout": "PT1.5S", "window": 1}"#, ms(1500)),
+        (r#"{"timeout": "P1DT2H", "window": 1}"#, ms(93_600_000)),
+        (r#"{"timeout": "PT0,5S", "window": 1}"#, ms(500)),
+    ];
+    for (json, expected) in cases {
+        let config: Config = serde_json::from_str(json).unwrap();
+        assert_eq!(config.timeout, expected, "input {}", json);
+    }
+}
+
+#[test]
+fn test_duration_units_and_options() {
+    let json = r#"{"timeout": 1, "retry": "10", "window": "1.5"}"#;
+    let config: Config = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        config,
+        Config {
+            timeout: Duration::from_millis(1),
+            retry: Some(Duration::from_secs(10)),
+            window: Duration::from_secs(90),
+        }
+    );
+
+    let json = r#"{"timeout": 1, "retry": null, "window": 0}"#;
+    let config: Config = serde_json::from_str(json).unwrap();
+    assert_eq!(config.retry, None);
+}
+
+#[test]
+fn test_duration_errors() {
+    let cases = [
+        (
+            r#"{"timeout": -1, "window": 1}"#,
+            "-1 is not a valid duration",
+        ),
+        (r#"{"timeout": "-3s", "window": 1}"#, ""),
+        (
+            r#"{"timeout": "3 fortnights", "window": 1}"#,
+            "unknown unit",
+        ),
+        (r#"{"timeout": "P1M", "window": 1}"#, "no fixed length"),
+        (r#"{"timeout": "PT", "window": 1}"#, ""),
+        (r#"{"timeout": "s", "window": 1}"#, ""),
+        (r#"{"timeout": "1e30s", "window": 1}"#, ""),
+        (r#"{"timeout": true, "window": 1}"#, ""),
+    ];
+    for (json, message) in cases {
+        let err = serde_json::from_str::<Config>(json).unwrap_err();
+        assert!(err.to_string().contains(message), "{}: {}", json, err);
+    }
+
+    let json = r#"{"timeout": 1e300, "window": 1}"#;
+    let err = serde_json::from_str::<Config>(json)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("too large") && err.len() < 100, "{}", err);
+}
+
+#[test]
+fn test_duration_serialize() {
+    let row = Row {
+        millis: Duration::from_millis(1500),
+        human: Duration::from_millis(90_500),
+        iso: Some(Duration::from_secs(5400)),
+    };
+    let json = serde_json::to_string(&row).unwrap();
+    assert_eq!(json, r#"[1500,"1m30s500ms","PT1H30M"]"#);
+    assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), row);
+
+    let row = Row {
+        millis: Duration::from_micros(1),
+        human: Duration::ZERO,
+        iso: None,
+    };
+    let json = serde_json::to_string(&row).unwrap();
+    assert_eq!(json, r#"[0.001,"0s",null]"#);
+    assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), row);
+}
+
+#[test]
+fn test_duration_functions() {
+    #[derive(Debug, Deserialize, Serialize, PartialEq)]
+    struct Plain {
+        #[serde(
+            deserialize_with = "lenient_duration",
+            serialize_with = "serialize_duration_iso8601"
+        )]
+        timeout: Duration,
+        #[serde(deserialize_with = "lenient_duration_option", default)]
+        idle: Option<Duration>,
+    }
+
+    let plain: Plain = serde_json::from_str(r#"{"timeout": 90}"#).unwrap();
+    assert_eq!(plain.timeout, Duration::from_secs(90));
+    assert_eq!(plain.idle, None);
+    let json = serde_json::to_string(&plain).unwrap();
+    assert_eq!(json, r#"{"timeout":"PT1M30S","idle":null}"#);
+
+    let value = LenientValue::String("PT0.000000001S".into());
+    assert_eq!(
+        value.parse_duration(DurationUnit::Secs),
+        Ok(Duration::from_nanos(1))
+    );
+    assert_eq!(DurationUnit::from_suffix("MIN"), Some(DurationUnit::Mins));
+}
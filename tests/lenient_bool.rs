@@ -0,0 +1,165 @@
+use serde::Deserialize;
+use serde_tuplex::{Lenient, LenientValue, TupleLenient, lenient_bool, lenient_bool_option};
+
+#[derive(Debug, Lenient, PartialEq)]
+struct Settings {
+    enabled: bool,
+    verbose: Option<bool>,
+}
+
+#[derive(Debug, TupleLenient, PartialEq)]
+struct Row {
+    id: u32,
+    active: bool,
+}
+
+#[derive(Debug, Lenient)]
+struct Localized {
+    #[serde_tuplex(true_values = ["ja", "si"], false_values = ["nein", "no"])]
+    enabled: bool,
+    #[serde_tuplex(true_values = ["x"])]
+    checked: Option<bool>,
+}
+
+#[test]
+fn test_bool_truth_table() {
+    let truthy = [
+        r#"{"enabled": "true"}"#,
+        r#"{"enabled": "TRUE"}"#,
+        r#"{"enabled": "True"}"#,
+        r#"{"enabled": "t"}"#,
+        r#"{"enabled": "T"}"#,
+        r#"{"enabled": "yes"}"#,
+        r#"{"enabled": "YES"}"#,
+        r#"{"enabled": "y"}"#,
+        r#"{"enabled": "on"}"#,
+        r#"{"enabled": "On"}"#,
+        r#"{"enabled": "1"}"#,
+    ];
+    for json in truthy {
+        let s: Settings = serde_json::from_str(json).unwrap();
+        assert!(s.enabled, "{} should be true", json);
+    }
+
+    let falsy = [
+        r#"{"enabled": "false"}"#,
+        r#"{"enabled": "FALSE"}"#,
+        r#"{"enabled": "f"}"#,
+        r#"{"enabled": "no"}"#,
+        r#"{"enabled": "No"}"#,
+        r#"{"enabled": "n"}"#,
+        r#"{"enabled": "off"}"#,
+        r#"{"enabled": "OFF"}"#,
+        r#"{"enabled": "0"}"#,
+    ];
+    for json in falsy {
+        let s: Settings = serde_json::from_str(json).unwrap();
+        assert!(!s.enabled, "{} should be false", json);
+    }
+}
+
+#[test]
+fn test_bool_native_values() {
+    let s: Settings = serde_json::from_str(r#"{"enabled": true, "verbose": false}"#).unwrap();
+    assert_eq!(
+        s,
+        Settings {
+            enabled: true,
+            verbose: Some(false)
+        }
+    );
+
+    let s: Settings = serde_json::from_str(r#"{"enabled": 1, "verbose": 0}"#).unwrap();
+    assert!(s.enabled);
+    assert_eq!(s.verbose, Some(false));
+
+    let s: Settings = serde_json::from_str(r#"{"enabled": 0, "verbose": null}"#).unwrap();
+    assert!(!s.enabled);
+    assert_eq!(s.verbose, None);
+
+    let s: Settings = serde_json::from_str(r#"{"enabled": "on"}"#).unwrap();
+    assert_eq!(s.verbose, None);
+}
+
+#[test]
+fn test_bool_rejects_other_values() {
+    for json in [
+        r#"{"enabled": "maybe"}"#,
+        r#"{"enabled": ""}"#,
+        r#"{"enabled": 2}"#,
+        r#"{"enabled": -1}"#,
+        r#"{"enabled": 0.5}"#,
+    ] {
+        let result: Result<Settings, _> = serde_json::from_str(json);
+        assert!(result.is_err(), "{} should be rejected", json);
+    }
+
+    let err = serde_json::from_str::<Settings>(r#"{"enabled": "maybe"}"#)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("invalid bool \"maybe\""), "{}", err);
+}
+
+#[test]
+fn test_bool_tuple_lenient() {
+    let row: Row = serde_json::from_str(r#"["7", "yes"]"#).unwrap();
+    assert_eq!(
+        row,
+        Row {
+            id: 7,
+            active: true
+        }
+    );
+    assert_eq!(serde_json::to_string(&row).unwrap(), "[7,true]");
+}
+
+#[test]
+fn test_bool_custom_vocabulary() {
+    let l: Localized = serde_json::from_str(r#"{"enabled": "JA", "checked": "x"}"#).unwrap();
+    assert!(l.enabled);
+    assert_eq!(l.checked, Some(true));
+
+    let l: Localized = serde_json::from_str(r#"{"enabled": "nein", "checked": "no"}"#).unwrap();
+    assert!(!l.enabled);
+    // `false_values` not given: the default vocabulary applies.
+    assert_eq!(l.checked, Some(false));
+
+    let l: Localized = serde_json::from_str(r#"{"enabled": false, "checked": null}"#).unwrap();
+    assert!(!l.enabled);
+    assert_eq!(l.checked, None);
+
+    // The custom list replaces the default one.
+    assert!(serde_json::from_str::<Localized>(r#"{"enabled": "yes", "checked": null}"#).is_err());
+    assert!(serde_json::from_str::<Localized>(r#"{"enabled": "ja", "checked": "yes"}"#).is_err());
+}
+
+#[test]
+fn test_lenient_bool_functions() {
+    #[derive(Deserialize)]
+    struct Flags {
+        #[serde(deserialize_with = "lenient_bool")]
+        enabled: bool,
+        #[serde(deserialize_with = "lenient_bool_option", default)]
+        debug: Option<bool>,
+    }
+
+    let f: Flags = serde_json::from_str(r#"{"enabled": "Y", "debug": "off"}"#).unwrap();
+    assert!(f.enabled);
+    assert_eq!(f.debug, Some(false));
+
+    let f: Flags = serde_json::from_str(r#"{"enabled": true}"#).unwrap();
+    assert!(f.enabled);
+    assert_eq!(f.debug, None);
+
+    assert!(serde_json::from_str::<Flags>(r#"{"enabled": "nope"}"#).is_err());
+}
+
+#[test]
+fn test_lenient_value_bool() {
+    let value: LenientValue = serde_json::from_str("true").unwrap();
+    assert!(value.is_bool());
+    assert_eq!(value.as_bool(), Some(true));
+    assert_eq!(value.to_string(), "true");
+    assert_eq!(serde_json::to_string(&value).unwrap(), "true");
+    assert!(value.parse::<u32>().is_err());
+}
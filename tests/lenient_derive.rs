@@ -278,6 +278,55 @@ fn test_lenient_scientific_notation() {
     let json = r#"{"value": "1.23e10"}"#;
     let data: Scientific = serde_json::from_str(json).unwrap();
     assert_eq!(data.value, 1.23e10);
+
+    #[derive(Debug, Lenient, PartialEq)]
+    struct Integers {
+        count: u64,
+        delta: i32,
+        small: u8,
+        big: u128,
+    }
+
+    let json =
+        r#"{"count": "1e3", "delta": "-2.5E2", "small": 1e2, "big": "1.8446744073709551616e19"}"#;
+    let data: Integers = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        data,
+        Integers {
+            count: 1000,
+            delta: -250,
+            small: 100,
+            big: 18446744073709551616,
+        }
+    );
+
+    let parse = |count: &str| {
+        let json = format!(
+            r#"{{"count": {}, "delta": 0, "small": 0, "big": 0}}"#,
+            count
+        );
+        serde_json::from_str::<Integers>(&json).map_err(|e| e.to_string())
+    };
+    assert!(
+        parse(r#""1.5e0""#)
+            .unwrap_err()
+            .contains("is not an integer")
+    );
+    assert!(
+        parse(r#""1e-3""#)
+            .unwrap_err()
+            .contains("is not an integer")
+    );
+    assert!(parse(r#""1e20""#).unwrap_err().contains("out of range"));
+    assert!(parse(r#""-1e3""#).unwrap_err().contains("out of range"));
+    assert!(parse(r#""1e""#).is_err());
+    assert_eq!(parse(r#""0e999999""#).unwrap().count, 0);
+    assert!(
+        parse(r#""1e9223372036854775807""#)
+            .unwrap_err()
+            .contains("out of range")
+    );
+    assert!(parse(r#"".5e-9223372036854775808""#).is_err());
 }
 
 #[test]
@@ -286,3 +335,12 @@ fn test_lenient_preserves_non_numeric_fields() {
     let user: User = serde_json::from_str(json).unwrap();
     assert_eq!(user.name, "Test User 🚀");
 }
+
+#[test]
+fn test_lenient_from_reader() {
+    let json = br#"{"id": "7", "balance": "1.5", "age": "40", "name": "Reader"}"#;
+    let user: User = serde_json::from_reader(&json[..]).unwrap();
+    assert_eq!(user.id, 7);
+    assert_eq!(user.balance, 1.5);
+    assert_eq!(user.age, Some(40));
+}
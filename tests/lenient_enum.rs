@@ -0,0 +1,155 @@
+use serde::{Deserialize, Serialize};
+use serde_tuplex::{Lenient, LenientEnum};
+
+#[derive(Debug, Clone, Copy, PartialEq, LenientEnum)]
+#[serde_tuplex(case_insensitive)]
+enum Status {
+    Inactive = 0,
+    #[serde_tuplex(alias = "enabled", alias = "on")]
+    Active = 1,
+    #[serde_tuplex(rename = "SUSPENDED")]
+    Suspended = 5,
+}
+
+#[derive(Debug, PartialEq, LenientEnum)]
+#[serde_tuplex(serialize = "number")]
+enum Level {
+    Low,
+    Medium,
+    High,
+}
+
+#[derive(Debug, PartialEq, LenientEnum)]
+enum Strict {
+    Yes,
+    No,
+}
+
+#[repr(u64)]
+#[derive(Debug, PartialEq, LenientEnum)]
+#[serde_tuplex(serialize = "number")]
+enum Wide {
+    Big = u64::MAX,
+}
+
+#[repr(u128)]
+#[derive(Debug, PartialEq, LenientEnum)]
+#[serde_tuplex(serialize = "number")]
+enum Huge {
+    Max = i128::MAX as u128,
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+struct Account {
+    status: Status,
+    level: Option<Level>,
+}
+
+#[test]
+fn test_enum_from_discriminant() {
+    for json in ["1", r#""1""#, "1.0"] {
+        let status: Status = serde_json::from_str(json).unwrap();
+        assert_eq!(status, Status::Active, "input {}", json);
+    }
+    let status: Status = serde_json::from_str("5").unwrap();
+    assert_eq!(status, Status::Suspended);
+
+    let level: Level = serde_json::from_str("2").unwrap();
+    assert_eq!(level, Level::High);
+
+    let wide: Wide = serde_json::from_str("18446744073709551615").unwrap();
+    assert_eq!(wide, Wide::Big);
+
+    let huge: Huge = serde_json::from_str(r#""170141183460469231731687303715884105727""#).unwrap();
+    assert_eq!(huge, Huge::Max);
+}
+
+#[test]
+fn test_enum_from_name() {
+    for json in [
+        r#""Active""#,
+        r#""ACTIVE""#,
+        r#""active""#,
+        r#""Enabled""#,
+        r#""ON""#,
+    ] {
+        let status: Status = serde_json::from_str(json).unwrap();
+        assert_eq!(status, Status::Active, "input {}", json);
+    }
+    let status: Status = serde_json::from_str(r#""suspended""#).unwrap();
+    assert_eq!(status, Status::Suspended);
+
+    let level: Level = serde_json::from_str(r#""Medium""#).unwrap();
+    assert_eq!(level, Level::Medium);
+}
+
+#[test]
+fn test_enum_case_sensitive_by_default() {
+    let strict: Strict = serde_json::from_str(r#""Yes""#).unwrap();
+    assert_eq!(strict, Strict::Yes);
+    assert!(serde_json::from_str::<Strict>(r#""yes""#).is_err());
+    assert!(serde_json::from_str::<Level>(r#""low""#).is_err());
+}
+
+#[test]
+fn test_enum_errors() {
+    let err = serde_json::from_str::<Status>(r#""Deleted""#)
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains(
+            r#"unknown variant "Deleted" of Status, expected one of Inactive, Active, SUSPENDED"#
+        ),
+        "{}",
+        err
+    );
+
+    let err = serde_json::from_str::<Status>("3").unwrap_err().to_string();
+    assert!(err.contains("3 is not a discriminant of Status"), "{}", err);
+
+    assert!(serde_json::from_str::<Status>("1.5").is_err());
+    assert!(serde_json::from_str::<Status>("true").is_err());
+    assert!(serde_json::from_str::<Status>("null").is_err());
+}
+
+#[test]
+fn test_enum_serialize() {
+    assert_eq!(
+        serde_json::to_string(&Status::Active).unwrap(),
+        r#""Active""#
+    );
+    assert_eq!(
+        serde_json::to_string(&Status::Suspended).unwrap(),
+        r#""SUSPENDED""#
+    );
+    assert_eq!(serde_json::to_string(&Level::High).unwrap(), "2");
+    assert_eq!(
+        serde_json::to_string(&Wide::Big).unwrap(),
+        "18446744073709551615"
+    );
+}
+
+#[test]
+fn test_enum_as_field() {
+    let account: Account = serde_json::from_str(r#"{"status": "0", "level": "High"}"#).unwrap();
+    assert_eq!(
+        account,
+        Account {
+            status: Status::Inactive,
+            level: Some(Level::High)
+        }
+    );
+    assert_eq!(
+        serde_json::to_string(&account).unwrap(),
+        r#"{"status":"Inactive","level":2}"#
+    );
+
+    #[derive(Debug, Lenient)]
+    struct Row {
+        id: u32,
+        status: Status,
+    }
+    let row: Row = serde_json::from_str(r#"{"id": "9", "status": "enabled"}"#).unwrap();
+    assert_eq!(row.id, 9);
+    assert_eq!(row.status, Status::Active);
+}
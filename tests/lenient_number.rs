@@ -0,0 +1,104 @@
+use serde::{Deserialize, Serialize};
+use serde_tuplex::{LenientNumber, NumberSource};
+
+#[derive(Debug, Deserialize, Serialize)]
+struct Entry {
+    amount: LenientNumber,
+    fee: Option<LenientNumber>,
+}
+
+#[test]
+fn test_lenient_number_keeps_string_text() {
+    let n: LenientNumber = serde_json::from_str(r#""0012""#).unwrap();
+    assert_eq!(n.source(), NumberSource::String);
+    assert_eq!(n.raw(), "0012");
+    assert_eq!(n.to_string(), "0012");
+    assert_eq!(n.to_u64(), Some(12));
+    assert_eq!(n.to_decimal_str().as_deref(), Some("12"));
+    assert_eq!(serde_json::to_string(&n).unwrap(), r#""0012""#);
+}
+
+#[test]
+fn test_lenient_number_native_round_trip() {
+    let n: LenientNumber = serde_json::from_str("42").unwrap();
+    assert_eq!(n.source(), NumberSource::Native);
+    assert_eq!(n.raw(), "42");
+    assert_eq!(serde_json::to_string(&n).unwrap(), "42");
+
+    let n: LenientNumber = serde_json::from_str("-2.5").unwrap();
+    assert_eq!(n.to_f64(), Some(-2.5));
+    assert_eq!(serde_json::to_string(&n).unwrap(), "-2.5");
+
+    let n: LenientNumber = serde_json::from_str("18446744073709551615").unwrap();
+    assert_eq!(n.to_u64(), Some(u64::MAX));
+    assert_eq!(n.to_i64(), None);
+    assert_eq!(serde_json::to_string(&n).unwrap(), "18446744073709551615");
+}
+
+#[test]
+fn test_lenient_number_checked_conversions() {
+    let n = LenientNumber::from_string("1.50").unwrap();
+    assert_eq!(n.to_u64(), None);
+    assert_eq!(n.to_f64(), Some(1.5));
+    assert_eq!(n.to_decimal_str().as_deref(), Some("1.50"));
+
+    let n = LenientNumber::from_string("-12.000").unwrap();
+    assert_eq!(n.to_i128(), Some(-12));
+    assert_eq!(n.to_u64(), None);
+
+    let n = LenientNumber::from_string("1e3").unwrap();
+    assert_eq!(n.to_u64(), Some(1000));
+    assert_eq!(n.to_decimal_str().as_deref(), Some("1000"));
+
+    let n = LenientNumber::from_string("+2.5E-3").unwrap();
+    assert_eq!(n.to_decimal_str().as_deref(), Some("0.0025"));
+
+    let n = LenientNumber::from_string("1e400").unwrap();
+    assert_eq!(n.to_f64(), None);
+    assert_eq!(n.to_decimal_str().map(|s| s.len()), Some(401));
+
+    let n = LenientNumber::from_string("1e100000").unwrap();
+    assert_eq!(n.to_decimal_str(), None);
+    assert_eq!(n.to_i128(), None);
+
+    for extreme in ["1e9223372036854775807", ".5e-9223372036854775808"] {
+        let n: LenientNumber = serde_json::from_str(&format!("{:?}", extreme)).unwrap();
+        assert_eq!(n.to_decimal_str(), None);
+        assert_eq!(n.to_i128(), None);
+    }
+}
+
+#[test]
+fn test_lenient_number_rejects_non_numeric_strings() {
+    for input in [
+        r#""abc""#,
+        r#""""#,
+        r#""1.2.3""#,
+        r#""1e""#,
+        r#""NaN""#,
+        r#"" 1""#,
+    ] {
+        let result: Result<LenientNumber, _> = serde_json::from_str(input);
+        assert!(result.is_err(), "{} should be rejected", input);
+    }
+    assert!(LenientNumber::from_string(".").is_err());
+    assert!(LenientNumber::from_string(".5").is_ok());
+}
+
+#[test]
+fn test_lenient_number_as_field() {
+    let json = r#"{"amount": "1.50", "fee": 3}"#;
+    let entry: Entry = serde_json::from_str(json).unwrap();
+    assert_eq!(entry.amount.raw(), "1.50");
+    assert_eq!(
+        entry.fee.as_ref().map(|n| n.source()),
+        Some(NumberSource::Native)
+    );
+    assert_eq!(
+        serde_json::to_string(&entry).unwrap(),
+        r#"{"amount":"1.50","fee":3}"#
+    );
+
+    let entry: Entry = serde_json::from_str(r#"{"amount": 7, "fee": null}"#).unwrap();
+    assert!(entry.fee.is_none());
+}
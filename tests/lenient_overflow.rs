@@ -0,0 +1,154 @@
+use serde_tuplex::{Lenient, TupleLenient};
+
+#[derive(Debug, Lenient, PartialEq)]
+struct Saturated {
+    #[serde_tuplex(overflow = "saturate")]
+    percent: u8,
+    #[serde_tuplex(overflow = "saturate")]
+    count: u32,
+    #[serde_tuplex(overflow = "saturate")]
+    delta: Option<i16>,
+}
+
+#[derive(Debug, Lenient, PartialEq)]
+struct Wrapped {
+    #[serde_tuplex(overflow = "wrap")]
+    byte: u8,
+    #[serde_tuplex(overflow = "wrap")]
+    signed: i8,
+}
+
+#[derive(Debug, Lenient, PartialEq)]
+struct Strict {
+    #[serde_tuplex(overflow = "error")]
+    percent: u8,
+}
+
+#[derive(Debug, TupleLenient, PartialEq)]
+struct SaturatedRow {
+    #[serde_tuplex(overflow = "saturate")]
+    level: u8,
+    #[serde_tuplex(overflow = "saturate")]
+    ratio: f32,
+}
+
+// Saturate
+
+#[test]
+fn test_overflow_saturate_numbers() {
+    let json = r#"{"percent": 300, "count": -1, "delta": 40000}"#;
+    let data: Saturated = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        data,
+        Saturated {
+            percent: 255,
+            count: 0,
+            delta: Some(i16::MAX),
+        }
+    );
+}
+
+#[test]
+fn test_overflow_saturate_strings() {
+    let json = r#"{"percent": "300", "count": "-1", "delta": "-40000"}"#;
+    let data: Saturated = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        data,
+        Saturated {
+            percent: 255,
+            count: 0,
+            delta: Some(i16::MIN),
+        }
+    );
+}
+
+#[test]
+fn test_overflow_saturate_beyond_i128() {
+    let json = r#"{"percent": "999999999999999999999999999999999999999999", "count": "-999999999999999999999999999999999999999999", "delta": null}"#;
+    let data: Saturated = serde_json::from_str(json).unwrap();
+    assert_eq!(data.percent, 255);
+    assert_eq!(data.count, 0);
+    assert_eq!(data.delta, None);
+}
+
+#[test]
+fn test_overflow_saturate_floats() {
+    let json = r#"{"percent": 1e10, "count": "-3.5e9", "delta": 12.0}"#;
+    let data: Saturated = serde_json::from_str(json).unwrap();
+    assert_eq!(data.percent, 255);
+    assert_eq!(data.count, 0);
+    assert_eq!(data.delta, Some(12));
+}
+
+#[test]
+fn test_overflow_saturate_in_range_fraction_rejected() {
+    let json = r#"{"percent": 12.5, "count": 1, "delta": null}"#;
+    let result: Result<Saturated, _> = serde_json::from_str(json);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_overflow_saturate_invalid_string() {
+    let json = r#"{"percent": "lots", "count": 1, "delta": null}"#;
+    let result: Result<Saturated, _> = serde_json::from_str(json);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_overflow_saturate_float_target() {
+    let json = r#"[300, "1e39"]"#;
+    let row: SaturatedRow = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        row,
+        SaturatedRow {
+            level: 255,
+            ratio: f32::MAX,
+        }
+    );
+
+    let json = r#"[0, "-inf"]"#;
+    let row: SaturatedRow = serde_json::from_str(json).unwrap();
+    assert_eq!(row.ratio, f32::NEG_INFINITY);
+}
+
+// Wrap
+
+#[test]
+fn test_overflow_wrap() {
+    let json = r#"{"byte": 300, "signed": 200}"#;
+    let data: Wrapped = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        data,
+        Wrapped {
+            byte: 44,
+            signed: -56,
+        }
+    );
+}
+
+#[test]
+fn test_overflow_wrap_strings() {
+    let json = r#"{"byte": "-1", "signed": "340282366920938463463374607431768211457"}"#;
+    let data: Wrapped = serde_json::from_str(json).unwrap();
+    assert_eq!(data.byte, 255);
+    assert_eq!(data.signed, 1);
+
+    let json = r#"{"byte": "3e2", "signed": "2.00e2"}"#;
+    let data: Wrapped = serde_json::from_str(json).unwrap();
+    assert_eq!(data.byte, 44);
+    assert_eq!(data.signed, -56);
+    assert!(serde_json::from_str::<Wrapped>(r#"{"byte": "2.5e0", "signed": 0}"#).is_err());
+}
+
+// Error
+
+#[test]
+fn test_overflow_error_is_default_behaviour() {
+    let json = r#"{"percent": 300}"#;
+    let result: Result<Strict, _> = serde_json::from_str(json);
+    assert!(result.is_err());
+
+    let json = r#"{"percent": "200"}"#;
+    let data: Strict = serde_json::from_str(json).unwrap();
+    assert_eq!(data.percent, 200);
+}
@@ -0,0 +1,216 @@
+use serde::{Deserialize, Serialize};
+use serde_tuplex::{Lenient, LenientParse, LenientValue, TupleLenient};
+use std::num::{NonZero, NonZeroI64, NonZeroU32, Saturating, Wrapping};
+
+/// Price in cents, converted exactly from integers and decimal strings.
+#[derive(Debug, PartialEq, Serialize)]
+struct Price(i64);
+
+impl LenientParse for Price {
+    fn parse_i64(v: i64) -> Result<Self, String> {
+        v.checked_mul(100)
+            .map(Price)
+            .ok_or_else(|| "price too large".to_string())
+    }
+
+    fn parse_u64(v: u64) -> Result<Self, String> {
+        i64::try_from(v)
+            .map_err(|e| e.to_string())
+            .and_then(Self::parse_i64)
+    }
+
+    fn parse_f64(v: f64) -> Result<Self, String> {
+        Ok(Price((v * 100.0).round() as i64))
+    }
+
+    fn parse_str(s: &str) -> Result<Self, String> {
+        let (whole, frac) = s.split_once('.').unwrap_or((s, ""));
+        if frac.len() > 2 {
+            return Err(format!("too many decimals in {}", s));
+        }
+        format!("{}{:0<2}", whole, frac)
+            .parse()
+            .map(Price)
+            .map_err(|e| format!("invalid price: {}", e))
+    }
+}
+
+/// Quantity that only accepts string input through the default hooks.
+#[derive(Debug, PartialEq, Serialize)]
+struct Quantity(u32);
+
+impl LenientParse for Quantity {
+    fn parse_str(s: &str) -> Result<Self, String> {
+        s.parse().map(Quantity).map_err(|e| format!("{}", e))
+    }
+}
+
+#[derive(Debug, Lenient, PartialEq)]
+struct Order {
+    #[serde_tuplex(lenient)]
+    price: Price,
+    #[serde_tuplex(lenient)]
+    quantity: Quantity,
+    #[serde_tuplex(lenient)]
+    discount: Option<Price>,
+}
+
+#[derive(Debug, TupleLenient, PartialEq)]
+struct Fill {
+    #[serde_tuplex(lenient)]
+    price: Price,
+    #[serde_tuplex(lenient)]
+    quantity: Quantity,
+}
+
+#[test]
+fn test_newtype_from_numbers() {
+    let json = r#"{"price": 12, "quantity": 3, "discount": 0.5}"#;
+    let order: Order = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        order,
+        Order {
+            price: Price(1200),
+            quantity: Quantity(3),
+            discount: Some(Price(50)),
+        }
+    );
+}
+
+#[test]
+fn test_newtype_from_strings() {
+    let json = r#"{"price": "19.99", "quantity": "7", "discount": null}"#;
+    let order: Order = serde_json::from_str(json).unwrap();
+    assert_eq!(order.price, Price(1999));
+    assert_eq!(order.quantity, Quantity(7));
+    assert_eq!(order.discount, None);
+}
+
+#[test]
+fn test_newtype_errors() {
+    let json = r#"{"price": "1.999", "quantity": 1}"#;
+    let err = serde_json::from_str::<Order>(json).unwrap_err();
+    assert!(err.to_string().contains("too many decimals"));
+}
+
+#[test]
+fn test_newtype_tuple_lenient() {
+    let fill: Fill = serde_json::from_str(r#"["0.05", 100]"#).unwrap();
+    assert_eq!(
+        fill,
+        Fill {
+            price: Price(5),
+            quantity: Quantity(100),
+        }
+    );
+}
+
+#[test]
+fn test_newtype_with_lenient_function() {
+    #[derive(Debug, Deserialize)]
+    struct Line {
+        #[serde(deserialize_with = "serde_tuplex::lenient")]
+        price: Price,
+    }
+
+    let line: Line = serde_json::from_str(r#"{"price": 3}"#).unwrap();
+    assert_eq!(line.price, Price(300));
+}
+
+#[test]
+fn test_std_impls() {
+    let value: LenientValue = serde_json::from_str(r#""42""#).unwrap();
+    assert_eq!(value.parse::<NonZeroU32>().unwrap().get(), 42);
+    assert_eq!(value.parse::<Wrapping<u8>>().unwrap(), Wrapping(42));
+
+    let large: LenientValue = serde_json::from_str(r#""300""#).unwrap();
+    assert_eq!(large.parse::<Wrapping<u8>>(), Ok(Wrapping(44)));
+    assert_eq!(large.parse::<Saturating<i8>>(), Ok(Saturating(i8::MAX)));
+    assert!(large.parse::<NonZero<u8>>().is_err());
+
+    let zero: LenientValue = serde_json::from_str("0").unwrap();
+    assert!(zero.parse::<NonZeroU32>().is_err());
+
+    let flag: LenientValue = serde_json::from_str(r#""true""#).unwrap();
+    assert!(flag.parse::<bool>().unwrap());
+
+    let letter: LenientValue = serde_json::from_str(r#""x""#).unwrap();
+    assert_eq!(letter.parse::<char>().unwrap(), 'x');
+}
+
+#[test]
+fn test_std_wrapper_fields() {
+    #[derive(Debug, Lenient, PartialEq)]
+    struct Counters {
+        id: NonZeroU32,
+        delta: Option<std::num::NonZeroI64>,
+        total: Wrapping<u64>,
+        level: core::num::Saturating<u8>,
+        shard: Option<NonZero<u16>>,
+    }
+
+    #[derive(Debug, TupleLenient, PartialEq)]
+    struct Row {
+        id: NonZeroU32,
+        delta: Option<Wrapping<i32>>,
+        level: Saturating<u8>,
+    }
+
+    let json = r#"{"id": "7", "delta": "-3", "total": 1e3, "level": " 200 ", "shard": 4}"#;
+    let counters: Counters = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        counters,
+        Counters {
+            id: NonZeroU32::new(7).unwrap(),
+            delta: NonZeroI64::new(-3),
+            total: Wrapping(1000),
+            level: Saturating(200),
+            shard: NonZero::new(4),
+        }
+    );
+
+    let json = r#"{"id": 1, "delta": null, "total": "0", "level": "0"}"#;
+    let counters: Counters = serde_json::from_str(json).unwrap();
+    assert_eq!(counters.delta, None);
+    assert_eq!(counters.shard, None);
+
+    let err =
+        serde_json::from_str::<Counters>(r#"{"id": "0", "total": 0, "level": 0}"#).unwrap_err();
+    assert!(err.to_string().contains("non-zero"));
+    assert!(
+        serde_json::from_str::<Counters>(r#"{"id": 1, "delta": 0, "total": 0, "level": 0}"#)
+            .is_err()
+    );
+
+    let json = r#"{"id": 1, "total": "-1", "level": 300}"#;
+    let counters: Counters = serde_json::from_str(json).unwrap();
+    assert_eq!(counters.total, Wrapping(u64::MAX));
+    assert_eq!(counters.level, Saturating(u8::MAX));
+    let json = r#"{"id": 1, "total": 1.5, "level": 0}"#;
+    assert!(serde_json::from_str::<Counters>(json).is_err());
+    let json = r#"{"id": 5000000000, "total": 0, "level": 0}"#;
+    assert!(serde_json::from_str::<Counters>(json).is_err());
+
+    #[derive(Debug, Lenient)]
+    struct Wide {
+        value: Wrapping<u128>,
+    }
+
+    let wide: Wide =
+        serde_json::from_str(r#"{"value": 170141183460469231731687303715884105728}"#).unwrap();
+    assert_eq!(wide.value, Wrapping(1 << 127));
+    let wide: Wide =
+        serde_json::from_str(r#"{"value": 340282366920938539021238333346091630592}"#).unwrap();
+    assert_eq!(wide.value, Wrapping(1 << 76));
+    let wide: Wide = serde_json::from_str(r#"{"value": -1.0}"#).unwrap();
+    assert_eq!(wide.value, Wrapping(u128::MAX));
+
+    let row: Row = serde_json::from_str(r#"["5", "-1", "9"]"#).unwrap();
+    let expected = Row {
+        id: NonZeroU32::new(5).unwrap(),
+        delta: Some(Wrapping(-1)),
+        level: Saturating(9),
+    };
+    assert_eq!(row, expected);
+    assert_eq!(serde_json::to_string(&row).unwrap(), "[5,-1,9]");
+}
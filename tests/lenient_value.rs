@@ -1,4 +1,8 @@
-use serde_tuplex::__private::LenientValue;
+use serde::Deserialize;
+use serde::de::IntoDeserializer;
+use serde::de::value::Error as ValueError;
+use serde_tuplex::LenientValue;
+use std::borrow::Cow;
 
 #[test]
 fn test_lenient_value_from_string() {
@@ -134,3 +138,240 @@ fn test_lenient_value_all_numeric_types() {
     assert_eq!(value.parse::<i32>().unwrap(), 123i32);
     assert_eq!(value.parse::<i64>().unwrap(), 123i64);
 }
+
+#[test]
+fn test_lenient_value_out_of_range_number() {
+    let value: LenientValue = serde_json::from_str("300").unwrap();
+    let result: Result<u8, String> = value.parse();
+    assert_eq!(result.unwrap_err(), "300 is out of range for u8");
+
+    let value: LenientValue = serde_json::from_str("-1").unwrap();
+    let result: Result<u32, String> = value.parse();
+    assert_eq!(result.unwrap_err(), "-1 is out of range for u32");
+}
+
+#[test]
+fn test_lenient_value_fractional_float_to_int() {
+    let value: LenientValue = serde_json::from_str("42.5").unwrap();
+    let result: Result<i64, String> = value.parse();
+    assert_eq!(result.unwrap_err(), "42.5 is not an integer");
+}
+
+#[test]
+fn test_lenient_value_float_bounds_to_int() {
+    // As an f64 this rounds up to 2^64; the exact digits are in range.
+    let value: LenientValue = serde_json::from_str("18446744073709551615.0").unwrap();
+    if cfg!(feature = "arbitrary_precision") {
+        assert_eq!(value.parse::<u64>(), Ok(u64::MAX));
+    } else {
+        assert!(value.parse::<u64>().is_err());
+    }
+
+    let value: LenientValue = serde_json::from_str("-9223372036854775808.0").unwrap();
+    assert_eq!(value.parse::<i64>().unwrap(), i64::MIN);
+
+    let value: LenientValue = serde_json::from_str("1e20").unwrap();
+    assert_eq!(value.parse::<u128>().unwrap(), 100_000_000_000_000_000_000);
+}
+
+#[test]
+fn test_lenient_value_integer_to_float() {
+    let value: LenientValue = serde_json::from_str("-7").unwrap();
+    assert_eq!(value.parse::<f64>().unwrap(), -7.0);
+    assert_eq!(value.parse::<f32>().unwrap(), -7.0);
+}
+
+#[test]
+fn test_lenient_value_string_error_message() {
+    let value: LenientValue = serde_json::from_str(r#""12a""#).unwrap();
+    let result: Result<u16, String> = value.parse();
+    assert_eq!(
+        result.unwrap_err(),
+        r#"invalid u16 "12a": invalid digit found in string"#
+    );
+}
+
+#[test]
+fn test_lenient_value_borrows_from_input() {
+    let json = r#""12345""#;
+    let value: LenientValue = serde_json::from_str(json).unwrap();
+    assert!(matches!(
+        value,
+        LenientValue::String(Cow::Borrowed("12345"))
+    ));
+    assert_eq!(value.parse::<u32>().unwrap(), 12345);
+}
+
+#[test]
+fn test_lenient_value_owned_when_escaped() {
+    let json = r#""\u0031\u0032""#;
+    let value: LenientValue = serde_json::from_str(json).unwrap();
+    assert!(matches!(&value, LenientValue::String(Cow::Owned(s)) if s == "12"));
+    assert_eq!(value.parse::<u8>().unwrap(), 12);
+}
+
+#[test]
+fn test_lenient_value_owned_from_reader() {
+    let mut deserializer = serde_json::Deserializer::from_reader(&br#""77""#[..]);
+    let value = LenientValue::deserialize(&mut deserializer).unwrap();
+    assert!(matches!(value, LenientValue::String(Cow::Owned(_))));
+    assert_eq!(value.parse::<i32>().unwrap(), 77);
+}
+
+#[test]
+fn test_lenient_value_into_owned() {
+    let owned: LenientValue<'static> = {
+        let json = String::from(r#""-5""#);
+        let value: LenientValue = serde_json::from_str(&json).unwrap();
+        value.into_owned()
+    };
+    assert_eq!(owned.parse::<i8>().unwrap(), -5);
+}
+
+fn from_native<T: IntoDeserializer<'static, ValueError>>(v: T) -> LenientValue<'static> {
+    LenientValue::deserialize(v.into_deserializer()).unwrap()
+}
+
+#[test]
+fn test_lenient_value_u128_extremes() {
+    let max = from_native(u128::MAX);
+    assert!(matches!(max, LenientValue::U128(u128::MAX)));
+    assert_eq!(max.parse::<u128>().unwrap(), u128::MAX);
+    assert!(max.parse::<i128>().is_err());
+    assert!(max.parse::<u64>().is_err());
+
+    let min = from_native(0u128);
+    assert_eq!(min.parse::<u128>().unwrap(), 0);
+    assert_eq!(min.parse::<u8>().unwrap(), 0);
+}
+
+#[test]
+fn test_lenient_value_i128_extremes() {
+    let min = from_native(i128::MIN);
+    assert!(matches!(min, LenientValue::I128(i128::MIN)));
+    assert_eq!(min.parse::<i128>().unwrap(), i128::MIN);
+    assert!(min.parse::<u128>().is_err());
+
+    let max = from_native(i128::MAX);
+    assert_eq!(max.parse::<i128>().unwrap(), i128::MAX);
+    assert_eq!(max.parse::<u128>().unwrap(), i128::MAX as u128);
+}
+
+#[test]
+fn test_lenient_value_128_to_narrow_types() {
+    let value = from_native(42i128);
+    assert_eq!(value.parse::<u8>().unwrap(), 42);
+    assert_eq!(value.parse::<f64>().unwrap(), 42.0);
+
+    let value = from_native(1u128 << 64);
+    assert_eq!(
+        value.parse::<u64>().unwrap_err(),
+        "18446744073709551616 is out of range for u64"
+    );
+}
+
+#[test]
+fn test_lenient_value_128_saturating_and_wrapping() {
+    let value = from_native(u128::MAX);
+    assert_eq!(value.parse_saturating::<i128>().unwrap(), i128::MAX);
+    assert_eq!(value.parse_wrapping::<u8>().unwrap(), u8::MAX);
+
+    let value = from_native(i128::MIN);
+    assert_eq!(value.parse_saturating::<i64>().unwrap(), i64::MIN);
+    assert_eq!(value.parse_wrapping::<i64>().unwrap(), 0);
+}
+
+#[test]
+fn test_lenient_value_128_strings() {
+    let json = r#""340282366920938463463374607431768211455""#;
+    let value: LenientValue = serde_json::from_str(json).unwrap();
+    assert_eq!(value.parse::<u128>().unwrap(), u128::MAX);
+
+    let json = r#""-170141183460469231731687303715884105728""#;
+    let value: LenientValue = serde_json::from_str(json).unwrap();
+    assert_eq!(value.parse::<i128>().unwrap(), i128::MIN);
+}
+
+#[test]
+fn test_lenient_value_classification() {
+    let string: LenientValue = serde_json::from_str(r#""1.5""#).unwrap();
+    assert!(string.is_string() && !string.is_integer() && !string.is_float());
+
+    let integer: LenientValue = serde_json::from_str("-3").unwrap();
+    assert!(!integer.is_string() && integer.is_integer() && !integer.is_float());
+
+    let float: LenientValue = serde_json::from_str("1.5").unwrap();
+    assert!(!float.is_string() && !float.is_integer() && float.is_float());
+
+    assert!(LenientValue::Number("12".into()).is_integer());
+    assert!(LenientValue::Number("1e3".into()).is_float());
+}
+
+#[test]
+fn test_lenient_value_accessors() {
+    let value = LenientValue::String("42".into());
+    assert_eq!(value.as_str(), Some("42"));
+    assert_eq!(value.as_u64(), None);
+    assert_eq!(value.as_f64(), None);
+
+    let value = LenientValue::I64(-1);
+    assert_eq!(value.as_i64(), Some(-1));
+    assert_eq!(value.as_u64(), None);
+    assert_eq!(value.as_i128(), Some(-1));
+    assert_eq!(value.as_f64(), Some(-1.0));
+    assert_eq!(value.as_str(), None);
+
+    let value = LenientValue::U128(u128::MAX);
+    assert_eq!(value.as_u128(), Some(u128::MAX));
+    assert_eq!(value.as_i128(), None);
+    assert_eq!(value.as_u64(), None);
+
+    let value = LenientValue::F64(2.0);
+    assert_eq!(value.as_i64(), None);
+    assert_eq!(value.as_f64(), Some(2.0));
+
+    let value = LenientValue::Number("18446744073709551616".into());
+    assert_eq!(value.as_u64(), None);
+    assert_eq!(value.as_u128(), Some(1 << 64));
+}
+
+#[test]
+fn test_lenient_value_display() {
+    assert_eq!(LenientValue::String("abc".into()).to_string(), "abc");
+    assert_eq!(LenientValue::Number("1.50".into()).to_string(), "1.50");
+    assert_eq!(LenientValue::I64(-5).to_string(), "-5");
+    assert_eq!(
+        LenientValue::U128(u128::MAX).to_string(),
+        u128::MAX.to_string()
+    );
+    assert_eq!(LenientValue::F64(0.25).to_string(), "0.25");
+}
+
+#[test]
+fn test_lenient_value_serialize_round_trip() {
+    for json in [r#""007""#, "42", "-42", "2.5"] {
+        let value: LenientValue = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_string(&value).unwrap(), json);
+    }
+    assert_eq!(
+        serde_json::to_string(&LenientValue::Number("12".into())).unwrap(),
+        "12"
+    );
+}
+
+#[test]
+fn test_lenient_value_equality() {
+    let a: LenientValue = serde_json::from_str(r#""1""#).unwrap();
+    assert_eq!(a, LenientValue::String("1".into()));
+    assert_ne!(a, LenientValue::I64(1));
+    assert_eq!(a.clone().into_owned(), a);
+}
+
+#[test]
+fn test_optional_lenient_value() {
+    let none: Option<LenientValue> = serde_json::from_str("null").unwrap();
+    assert_eq!(none, None);
+
+    let some: Option<LenientValue> = serde_json::from_str(r#""5""#).unwrap();
+    assert_eq!(some, Some(LenientValue::String("5".into())));
+}
@@ -0,0 +1,101 @@
+use serde::Serialize;
+use serde_tuplex::{Lenient, Tuple, TupleLenient, serialize_non_finite};
+
+#[derive(Debug, Lenient, PartialEq)]
+struct Policies {
+    allowed: f64,
+    #[serde_tuplex(non_finite = "reject")]
+    rejected: f32,
+    #[serde_tuplex(non_finite = "null")]
+    nulled: Option<f64>,
+}
+
+#[derive(Debug, TupleLenient)]
+#[serde_tuplex(non_finite = "null")]
+struct Row {
+    value: f64,
+    optional: Option<f64>,
+    #[serde_tuplex(non_finite = "allow")]
+    raw: f64,
+}
+
+#[derive(Debug, Tuple)]
+struct Output {
+    #[serde_tuplex(serialize = "non_finite_string")]
+    value: f64,
+    #[serde_tuplex(serialize = "non_finite_string")]
+    narrow: Option<f32>,
+    plain: f64,
+}
+
+#[test]
+fn test_non_finite_policies() {
+    let json = r#"{"allowed": "inf", "rejected": "1.5", "nulled": "NaN"}"#;
+    let parsed: Policies = serde_json::from_str(json).unwrap();
+    assert_eq!(parsed.allowed, f64::INFINITY);
+    assert_eq!(parsed.rejected, 1.5);
+    assert_eq!(parsed.nulled, None);
+
+    let json = r#"{"allowed": 1, "rejected": "-infinity", "nulled": 2.5}"#;
+    let err = serde_json::from_str::<Policies>(json).unwrap_err();
+    assert!(err.to_string().contains("-inf is not a finite number"));
+}
+
+#[test]
+fn test_non_finite_container() {
+    let row: Row = serde_json::from_str(r#"["1.5", "Infinity", "NaN"]"#).unwrap();
+    assert_eq!(row.value, 1.5);
+    assert_eq!(row.optional, None);
+    assert!(row.raw.is_nan());
+
+    // `null` falls back to rejecting on fields that are not `Option`.
+    let err = serde_json::from_str::<Row>(r#"["NaN", 1, 1]"#).unwrap_err();
+    assert!(err.to_string().contains("NaN is not a finite number"));
+}
+
+#[test]
+fn test_non_finite_serialize() {
+    let output = Output {
+        value: f64::NAN,
+        narrow: Some(f32::NEG_INFINITY),
+        plain: 2.5,
+    };
+    assert_eq!(
+        serde_json::to_string(&output).unwrap(),
+        r#"["NaN","-Infinity",2.5]"#
+    );
+
+    let output = Output {
+        value: f64::INFINITY,
+        narrow: None,
+        plain: 0.0,
+    };
+    assert_eq!(
+        serde_json::to_string(&output).unwrap(),
+        r#"["Infinity",null,0.0]"#
+    );
+
+    #[derive(Serialize)]
+    struct Manual {
+        #[serde(serialize_with = "serialize_non_finite")]
+        value: f32,
+    }
+    let json = serde_json::to_string(&Manual { value: 0.1 }).unwrap();
+    assert_eq!(json, r#"{"value":0.1}"#);
+}
+
+#[test]
+fn test_non_finite_round_trip() {
+    #[derive(Debug, TupleLenient)]
+    struct Sample {
+        #[serde_tuplex(serialize = "non_finite_string")]
+        value: f64,
+    }
+
+    let json = serde_json::to_string(&Sample {
+        value: f64::NEG_INFINITY,
+    })
+    .unwrap();
+    let back: Sample = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.value, f64::NEG_INFINITY);
+}
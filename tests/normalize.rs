@@ -0,0 +1,103 @@
+use serde::Deserialize;
+use serde_tuplex::{Lenient, LenientValue, Normalize, TupleLenient, lenient};
+
+#[derive(Debug, Lenient, PartialEq)]
+struct Reading {
+    count: u32,
+    delta: i64,
+    ratio: f64,
+    enabled: bool,
+    #[serde_tuplex(stringify)]
+    label: String,
+}
+
+#[derive(Debug, Lenient, PartialEq)]
+struct Selective {
+    #[serde_tuplex(normalize(trim))]
+    trimmed: u32,
+    #[serde_tuplex(normalize = "none")]
+    raw: u32,
+    #[serde_tuplex(normalize(trim, underscores), overflow = "saturate")]
+    small: u8,
+}
+
+#[derive(Debug, TupleLenient, PartialEq)]
+#[serde_tuplex(normalize = "none")]
+struct StrictRow {
+    a: u32,
+    #[serde_tuplex(normalize = "default")]
+    b: u32,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Manual {
+    #[serde(deserialize_with = "lenient")]
+    value: i32,
+}
+
+#[test]
+fn test_default_steps() {
+    let json = r#"{"count": " +1_000\n", "delta": "−４２", "ratio": "１．５", "enabled": " yes ", "label": " a_b "}"#;
+    let reading: Reading = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        reading,
+        Reading {
+            count: 1000,
+            delta: -42,
+            ratio: 1.5,
+            enabled: true,
+            label: " a_b ".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_field_steps() {
+    let json = r#"{"trimmed": " 7 ", "raw": "8", "small": " 1_000 "}"#;
+    let parsed: Selective = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        parsed,
+        Selective {
+            trimmed: 7,
+            raw: 8,
+            small: 255,
+        }
+    );
+
+    let json = r#"{"trimmed": "1_000", "raw": "8", "small": "1"}"#;
+    assert!(serde_json::from_str::<Selective>(json).is_err());
+    let json = r#"{"trimmed": "7", "raw": " 8", "small": "1"}"#;
+    assert!(serde_json::from_str::<Selective>(json).is_err());
+}
+
+#[test]
+fn test_container_steps() {
+    let row: StrictRow = serde_json::from_str(r#"["1", " 2 "]"#).unwrap();
+    assert_eq!(row, StrictRow { a: 1, b: 2 });
+    assert!(serde_json::from_str::<StrictRow>(r#"[" 1", "2"]"#).is_err());
+}
+
+#[test]
+fn test_lenient_fn_normalizes() {
+    let m: Manual = serde_json::from_str(r#"{"value": " −1_234 "}"#).unwrap();
+    assert_eq!(m.value, -1234);
+}
+
+#[test]
+fn test_apply_steps() {
+    assert_eq!(Normalize::NONE.apply(" +1 "), " +1 ");
+    assert_eq!((Normalize::TRIM | Normalize::PLUS_SIGN).apply(" +1 "), "1");
+    assert_eq!(Normalize::UNDERSCORES.apply("_1__0_"), "_1__0_");
+    assert_eq!(Normalize::UNDERSCORES.apply("0xdead_beef"), "0xdeadbeef");
+    assert_eq!(Normalize::UNDERSCORES.apply("-0Xa_b"), "-0Xab");
+    assert_eq!(Normalize::UNDERSCORES.apply("a_b"), "a_b");
+    assert_eq!(Normalize::UNDERSCORES.apply("1_e5"), "1_e5");
+    assert!(LenientValue::String("1_e5".into()).parse::<f64>().is_err());
+    assert_eq!(Normalize::DEFAULT.apply("＋１２"), "12");
+    assert!(Normalize::DEFAULT.contains(Normalize::FULL_WIDTH));
+
+    let value = LenientValue::String(" 5 ".into());
+    assert_eq!(value.normalized(Normalize::TRIM).as_str(), Some("5"));
+    assert_eq!(value.parse::<u8>(), Ok(5));
+    assert!(value.parse::<char>().is_err());
+}
@@ -0,0 +1,124 @@
+use serde_tuplex::{Lenient, LenientValue, NumberFormat, TupleLenient};
+
+#[derive(Debug, Lenient, PartialEq)]
+struct Invoice {
+    #[serde_tuplex(number_format = "de")]
+    total: f64,
+    #[serde_tuplex(number_format = "en")]
+    quantity: Option<u32>,
+    #[serde_tuplex(number_format(grouping = "_ ", decimal = ","))]
+    weight: f32,
+}
+
+#[derive(Debug, TupleLenient, PartialEq)]
+#[serde_tuplex(number_format = "fr")]
+struct Row {
+    amount: f64,
+    count: u64,
+    #[serde_tuplex(number_format = "ch")]
+    price: f64,
+    #[serde_tuplex(skip)]
+    strict: u8,
+}
+
+#[derive(Debug, Lenient, PartialEq)]
+struct Saturated {
+    #[serde_tuplex(number_format = "en", overflow = "saturate")]
+    small: u8,
+}
+
+#[test]
+fn test_number_format_profiles() {
+    let json = r#"{"total": "1.234,56", "quantity": "$1,234", "weight": "1_000,5"}"#;
+    let invoice: Invoice = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        invoice,
+        Invoice {
+            total: 1234.56,
+            quantity: Some(1234),
+            weight: 1000.5,
+        }
+    );
+
+    let json = r#"{"total": "-€ 12,5", "quantity": null, "weight": "2 000"}"#;
+    let invoice: Invoice = serde_json::from_str(json).unwrap();
+    assert_eq!(invoice.total, -12.5);
+    assert_eq!(invoice.quantity, None);
+    assert_eq!(invoice.weight, 2000.0);
+}
+
+#[test]
+fn test_number_format_leaves_native_numbers() {
+    let json = r#"{"total": 1234.5, "quantity": 7, "weight": 1}"#;
+    let invoice: Invoice = serde_json::from_str(json).unwrap();
+    assert_eq!(invoice.total, 1234.5);
+    assert_eq!(invoice.quantity, Some(7));
+}
+
+#[test]
+fn test_number_format_rejects_ambiguous_grouping() {
+    for json in [
+        r#"{"total": "1.5", "quantity": null, "weight": 0}"#,
+        r#"{"total": "1.23,4.5", "quantity": null, "weight": 0}"#,
+        r#"{"total": "1,2,3", "quantity": null, "weight": 0}"#,
+        r#"{"total": "12.34.567", "quantity": null, "weight": 0}"#,
+    ] {
+        assert!(
+            serde_json::from_str::<Invoice>(json).is_err(),
+            "{} should be rejected",
+            json
+        );
+    }
+
+    let json = r#"{"total": 0, "quantity": "1,5", "weight": 0}"#;
+    let err = serde_json::from_str::<Invoice>(json)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("misplaced grouping separator"), "{}", err);
+}
+
+#[test]
+fn test_number_format_container() {
+    let json = "[\"1\u{a0}234,5\", \"12 345\", \"1'234.5\", 3]";
+    let row: Row = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        row,
+        Row {
+            amount: 1234.5,
+            count: 12345,
+            price: 1234.5,
+            strict: 3
+        }
+    );
+}
+
+#[test]
+fn test_number_format_with_overflow() {
+    let s: Saturated = serde_json::from_str(r#"{"small": "1,000"}"#).unwrap();
+    assert_eq!(s.small, 255);
+}
+
+#[test]
+fn test_number_format_normalize() {
+    assert_eq!(NumberFormat::EN.normalize("1234.5").unwrap(), "1234.5");
+    assert_eq!(
+        NumberFormat::EN.normalize("1,234,567.5").unwrap(),
+        "1234567.5"
+    );
+    assert_eq!(NumberFormat::EN.normalize("$-1,234").unwrap(), "-1234");
+    assert_eq!(NumberFormat::EN.normalize("-$1,234").unwrap(), "-1234");
+    assert_eq!(NumberFormat::EN.normalize("12 £").unwrap(), "12");
+    assert_eq!(NumberFormat::DE.normalize("0,5").unwrap(), "0.5");
+    assert_eq!(
+        NumberFormat::new(&[], ',').normalize("12,5").unwrap(),
+        "12.5"
+    );
+    assert!(NumberFormat::DE.normalize("1,2,3").is_err());
+    assert!(NumberFormat::EN.normalize("1,234.5,6").is_err());
+    assert_eq!(NumberFormat::profile("de"), Some(NumberFormat::DE));
+    assert_eq!(NumberFormat::profile("xx"), None);
+
+    let value = LenientValue::String("1.234".into());
+    let normalized = value.normalize_with(&NumberFormat::DE).unwrap();
+    assert_eq!(normalized.parse::<u32>(), Ok(1234));
+}
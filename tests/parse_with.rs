@@ -0,0 +1,101 @@
+use serde_tuplex::{Lenient, LenientValue, TupleLenient};
+
+/// Basis points: `"125bp"` or a plain number.
+fn basis_points(value: &LenientValue) -> Result<u32, String> {
+    match value {
+        LenientValue::String(s) => s
+            .strip_suffix("bp")
+            .unwrap_or(s)
+            .parse()
+            .map_err(|e| format!("invalid basis points: {}", e)),
+        other => other.parse(),
+    }
+}
+
+/// Counts with a `k` suffix: `"1.5k"` -> 1500.
+fn kilo(s: &str) -> Result<u64, std::num::ParseFloatError> {
+    match s.strip_suffix('k') {
+        Some(n) => n.parse::<f64>().map(|v| (v * 1000.0) as u64),
+        None => s.parse::<f64>().map(|v| v as u64),
+    }
+}
+
+/// Percentages as ratios.
+fn percent(v: f64) -> Result<f64, &'static str> {
+    if v.is_finite() {
+        Ok(v / 100.0)
+    } else {
+        Err("not a finite percentage")
+    }
+}
+
+#[derive(Debug, Lenient, PartialEq)]
+struct Quote {
+    #[serde_tuplex(parse_with = "basis_points")]
+    spread: u32,
+    #[serde_tuplex(parse_with = "kilo")]
+    volume: u64,
+    #[serde_tuplex(parse_with = "percent")]
+    change: Option<f64>,
+    price: f64,
+}
+
+#[derive(Debug, TupleLenient, PartialEq)]
+struct Row {
+    #[serde_tuplex(parse_with = "kilo", max = 10_000)]
+    volume: u64,
+    count: u32,
+}
+
+#[test]
+fn test_parse_with_value_parser() {
+    let json = r#"{"spread": "125bp", "volume": 10, "change": 50, "price": "9.5"}"#;
+    let quote: Quote = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        quote,
+        Quote {
+            spread: 125,
+            volume: 10,
+            change: Some(0.5),
+            price: 9.5,
+        }
+    );
+}
+
+#[test]
+fn test_parse_with_str_parser_receives_numbers() {
+    let json = r#"{"spread": 7, "volume": "1.5k", "change": null, "price": 1}"#;
+    let quote: Quote = serde_json::from_str(json).unwrap();
+    assert_eq!(quote.spread, 7);
+    assert_eq!(quote.volume, 1500);
+    assert_eq!(quote.change, None);
+}
+
+#[test]
+fn test_parse_with_float_parser_accepts_strings() {
+    let json = r#"{"spread": 0, "volume": 0, "change": "-25", "price": 1}"#;
+    let quote: Quote = serde_json::from_str(json).unwrap();
+    assert_eq!(quote.change, Some(-0.25));
+}
+
+#[test]
+fn test_parse_with_error_message() {
+    let json = r#"{"spread": "abcbp", "volume": 0, "price": 1}"#;
+    let err = serde_json::from_str::<Quote>(json).unwrap_err();
+    assert!(err.to_string().contains("failed to parse field spread"));
+    assert!(err.to_string().contains("invalid basis points"));
+}
+
+#[test]
+fn test_parse_with_tuple_and_range() {
+    let row: Row = serde_json::from_str(r#"["2k", "3"]"#).unwrap();
+    assert_eq!(
+        row,
+        Row {
+            volume: 2000,
+            count: 3
+        }
+    );
+
+    assert!(serde_json::from_str::<Row>(r#"["20k", 3]"#).is_err());
+}
@@ -0,0 +1,125 @@
+use serde::{Deserialize, Serialize};
+use serde_tuplex::{
+    Lenient, LenientValue, PercentMode, TupleLenient, lenient_percent, lenient_percent_option,
+    serialize_percent,
+};
+
+#[derive(Debug, Lenient, PartialEq)]
+struct Stats {
+    #[serde_tuplex(percent)]
+    hit_rate: f64,
+    #[serde_tuplex(percent = "percent")]
+    cpu: Option<f32>,
+}
+
+#[derive(Debug, TupleLenient, PartialEq)]
+struct Row {
+    #[serde_tuplex(percent)]
+    ratio: f64,
+    #[serde_tuplex(percent = "percent")]
+    points: f64,
+    #[serde_tuplex(percent, serialize = "percent")]
+    string: Option<f64>,
+}
+
+#[test]
+fn test_percent_ratio_mode() {
+    let cases = [
+        (r#"{"hit_rate": 0.125}"#, 0.125),
+        (r#"{"hit_rate": "0.125"}"#, 0.125),
+        (r#"{"hit_rate": "12.5%"}"#, 0.125),
+        (r#"{"hit_rate": " 12.5 % "}"#, 0.125),
+        (r#"{"hit_rate": "33.3%"}"#, 0.333),
+        (r#"{"hit_rate": "-5%"}"#, -0.05),
+        (r#"{"hit_rate": "1e2%"}"#, 1.0),
+        (r#"{"hit_rate": 1}"#, 1.0),
+    ];
+    for (json, expected) in cases {
+        let stats: Stats = serde_json::from_str(json).unwrap();
+        assert_eq!(stats.hit_rate, expected, "input {}", json);
+    }
+
+    let err = serde_json::from_str::<Stats>(r#"{"hit_rate": "%"}"#).unwrap_err();
+    assert!(err.to_string().contains("invalid percentage"));
+    assert!(serde_json::from_str::<Stats>(r#"{"hit_rate": "abc%"}"#).is_err());
+    assert!(serde_json::from_str::<Stats>(r#"{"hit_rate": "12.5%%"}"#).is_err());
+}
+
+#[test]
+fn test_percent_percent_mode() {
+    let cases = [
+        (r#"{"hit_rate": 0, "cpu": 12.5}"#, Some(0.125)),
+        (r#"{"hit_rate": 0, "cpu": "12.5"}"#, Some(0.125)),
+        (r#"{"hit_rate": 0, "cpu": "12.5%"}"#, Some(0.125)),
+        (r#"{"hit_rate": 0, "cpu": 50}"#, Some(0.5)),
+        (r#"{"hit_rate": 0, "cpu": null}"#, None),
+    ];
+    for (json, expected) in cases {
+        let stats: Stats = serde_json::from_str(json).unwrap();
+        assert_eq!(stats.cpu, expected, "input {}", json);
+    }
+    assert!(serde_json::from_str::<Stats>(r#"{"hit_rate": 0, "cpu": true}"#).is_err());
+}
+
+#[test]
+fn test_percent_serialize() {
+    let row = Row {
+        ratio: 0.125,
+        points: 0.333,
+        string: Some(0.05),
+    };
+    let json = serde_json::to_string(&row).unwrap();
+    assert_eq!(json, r#"[0.125,33.3,"5%"]"#);
+    assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), row);
+
+    let row = Row {
+        ratio: 1.0,
+        points: 1.0,
+        string: None,
+    };
+    let json = serde_json::to_string(&row).unwrap();
+    assert_eq!(json, r#"[1.0,100.0,null]"#);
+    assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), row);
+}
+
+#[test]
+fn test_percent_functions() {
+    #[derive(Debug, Deserialize, Serialize, PartialEq)]
+    struct Plain {
+        #[serde(
+            deserialize_with = "lenient_percent",
+            serialize_with = "serialize_percent"
+        )]
+        rate: f64,
+        #[serde(deserialize_with = "lenient_percent_option", default)]
+        spare: Option<f64>,
+    }
+
+    let plain: Plain = serde_json::from_str(r#"{"rate": "12.5%", "spare": 0.5}"#).unwrap();
+    assert_eq!(plain.rate, 0.125);
+    assert_eq!(plain.spare, Some(0.5));
+    let json = serde_json::to_string(&plain).unwrap();
+    assert_eq!(json, r#"{"rate":"12.5%","spare":0.5}"#);
+
+    let err = serde_json::to_string(&Plain {
+        rate: f64::NAN,
+        spare: None,
+    })
+    .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("cannot be written as a percentage")
+    );
+
+    let value = LenientValue::String("0.1".into());
+    assert_eq!(value.parse_percent::<f32>(PercentMode::Percent), Ok(0.001));
+    assert_eq!(value.parse_percent::<f32>(PercentMode::Ratio), Ok(0.1));
+
+    for (text, expected) in [("1e2", 1.0), ("5E-1", 0.005), ("1.25e1", 0.125)] {
+        let value = LenientValue::Number(text.into());
+        assert_eq!(
+            value.parse_percent::<f64>(PercentMode::Percent),
+            Ok(expected)
+        );
+    }
+}
@@ -0,0 +1,174 @@
+use serde::{Deserialize, Serialize};
+use serde_tuplex::{
+    Lenient, RadixParse, TupleLenient, lenient_radix, lenient_radix_option, serialize_hex,
+    serialize_hex_option,
+};
+
+#[derive(Debug, Lenient, PartialEq)]
+struct Register {
+    #[serde_tuplex(radix_prefix)]
+    address: u32,
+    #[serde_tuplex(radix_prefix)]
+    offset: Option<i16>,
+    plain: u32,
+}
+
+#[derive(Debug, Lenient, PartialEq)]
+#[serde_tuplex(radix_prefix)]
+struct Block {
+    number: u64,
+    nonce: Option<u64>,
+    gas: f64,
+    #[serde_tuplex(skip)]
+    strict: u8,
+}
+
+#[derive(Debug, TupleLenient, PartialEq)]
+struct Word {
+    #[serde_tuplex(radix_prefix, serialize = "hex")]
+    value: u16,
+    #[serde_tuplex(radix_prefix, serialize = "hex")]
+    mask: Option<i8>,
+    count: u8,
+}
+
+#[test]
+fn test_radix_prefixes() {
+    let cases = [
+        (r#"{"address": "0x1f", "plain": 0}"#, 31),
+        (r#"{"address": "0X1F", "plain": 0}"#, 31),
+        (r#"{"address": "0o17", "plain": 0}"#, 15),
+        (r#"{"address": "0b1010", "plain": 0}"#, 10),
+        (r#"{"address": "+0x10", "plain": 0}"#, 16),
+        (r#"{"address": "0xdead_beef", "plain": 0}"#, 0xdead_beef),
+        (r#"{"address": "1_000", "plain": 0}"#, 1000),
+        (r#"{"address": "42", "plain": 0}"#, 42),
+        (r#"{"address": 42, "plain": 0}"#, 42),
+    ];
+    for (json, expected) in cases {
+        let register: Register = serde_json::from_str(json).unwrap();
+        assert_eq!(register.address, expected, "input {}", json);
+    }
+}
+
+#[test]
+fn test_radix_signed_and_option() {
+    let json = r#"{"address": "0x0", "offset": "-0x8000", "plain": 1}"#;
+    let register: Register = serde_json::from_str(json).unwrap();
+    assert_eq!(register.offset, Some(i16::MIN));
+
+    let json = r#"{"address": "0x0", "offset": null, "plain": 1}"#;
+    let register: Register = serde_json::from_str(json).unwrap();
+    assert_eq!(register.offset, None);
+}
+
+#[test]
+fn test_radix_rejects_invalid() {
+    for json in [
+        r#"{"address": "0x", "plain": 0}"#,
+        r#"{"address": "0x1g", "plain": 0}"#,
+        r#"{"address": "0b102", "plain": 0}"#,
+        r#"{"address": "0x1_0000_0000", "plain": 0}"#,
+        r#"{"address": "-0x1", "plain": 0}"#,
+        r#"{"address": "0x-1", "plain": 0}"#,
+        r#"{"address": "0x+1", "plain": 0}"#,
+        r#"{"address": "_", "plain": 0}"#,
+        r#"{"address": "0x_ff", "plain": 0}"#,
+        r#"{"address": "0xff_", "plain": 0}"#,
+        r#"{"address": "0x__f", "plain": 0}"#,
+        r#"{"address": "0x_ff_", "plain": 0}"#,
+        r#"{"address": "_1_", "plain": 0}"#,
+        r#"{"address": "1__0", "plain": 0}"#,
+    ] {
+        assert!(
+            serde_json::from_str::<Register>(json).is_err(),
+            "{} should be rejected",
+            json
+        );
+    }
+
+    // Only opted-in fields accept prefixes.
+    let json = r#"{"address": 1, "plain": "0x1"}"#;
+    assert!(serde_json::from_str::<Register>(json).is_err());
+}
+
+#[test]
+fn test_radix_container_attribute() {
+    let json = r#"{"number": "0x10", "nonce": "0b11", "gas": "1.5", "strict": 1}"#;
+    let block: Block = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        block,
+        Block {
+            number: 16,
+            nonce: Some(3),
+            gas: 1.5,
+            strict: 1
+        }
+    );
+
+    let json = r#"{"number": 1, "gas": 0, "strict": "0x1"}"#;
+    assert!(serde_json::from_str::<Block>(json).is_err());
+}
+
+#[test]
+fn test_radix_tuple_hex_round_trip() {
+    let word: Word = serde_json::from_str(r#"["0xbeef", "-0x10", "7"]"#).unwrap();
+    assert_eq!(
+        word,
+        Word {
+            value: 0xbeef,
+            mask: Some(-16),
+            count: 7
+        }
+    );
+    let json = serde_json::to_string(&word).unwrap();
+    assert_eq!(json, r#"["0xbeef","-0x10",7]"#);
+    assert_eq!(serde_json::from_str::<Word>(&json).unwrap(), word);
+
+    let word = Word {
+        value: 0,
+        mask: None,
+        count: 0,
+    };
+    assert_eq!(serde_json::to_string(&word).unwrap(), r#"["0x0",null,0]"#);
+}
+
+#[test]
+fn test_radix_functions() {
+    #[derive(Debug, Deserialize, Serialize, PartialEq)]
+    struct Tx {
+        #[serde(deserialize_with = "lenient_radix", serialize_with = "serialize_hex")]
+        value: u128,
+        #[serde(
+            deserialize_with = "lenient_radix_option",
+            serialize_with = "serialize_hex_option",
+            default
+        )]
+        fee: Option<u64>,
+    }
+
+    let tx: Tx = serde_json::from_str(r#"{"value": "0xde0b6b3a7640000", "fee": 21000}"#).unwrap();
+    assert_eq!(tx.value, 1_000_000_000_000_000_000);
+    assert_eq!(tx.fee, Some(21000));
+    assert_eq!(
+        serde_json::to_string(&tx).unwrap(),
+        r#"{"value":"0xde0b6b3a7640000","fee":"0x5208"}"#
+    );
+
+    let tx: Tx = serde_json::from_str(r#"{"value": 1}"#).unwrap();
+    assert_eq!(tx.fee, None);
+}
+
+#[test]
+fn test_radix_parse_trait() {
+    assert_eq!(u8::parse_radix_str("0xff"), Ok(255));
+    assert!(u8::parse_radix_str("0x100").is_err());
+    assert_eq!(i8::parse_radix_str("-0x80"), Ok(i8::MIN));
+    assert!(i8::parse_radix_str("0x80").is_err());
+    assert_eq!(
+        i128::parse_radix_str(&i128::MIN.to_hex_string()),
+        Ok(i128::MIN)
+    );
+    assert_eq!(u128::MAX.to_hex_string(), format!("0x{:x}", u128::MAX));
+    assert_eq!((-1i32).to_hex_string(), "-0x1");
+}
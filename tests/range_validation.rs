@@ -0,0 +1,136 @@
+use serde_tuplex::{Lenient, Tuple, TupleLenient};
+
+#[derive(Debug, Lenient, PartialEq)]
+struct Endpoint {
+    #[serde_tuplex(range = 1..=65535)]
+    port: u32,
+    #[serde_tuplex(range = 0.0..=1.0)]
+    ratio: f64,
+    #[serde_tuplex(min = 1)]
+    retries: Option<u8>,
+}
+
+#[derive(Debug, Lenient, PartialEq)]
+struct StrictBounds {
+    #[serde_tuplex(skip, max = 10)]
+    level: i32,
+    #[serde_tuplex(range = -5..5)]
+    offset: i64,
+}
+
+#[derive(Debug, TupleLenient, PartialEq)]
+struct Sample {
+    #[serde_tuplex(min = 0.0, max = 100.0)]
+    percent: f32,
+    id: u64,
+}
+
+#[derive(Debug, Tuple, PartialEq)]
+struct Pixel {
+    #[serde_tuplex(range = 0..=255)]
+    r: i32,
+    label: String,
+}
+
+#[test]
+fn test_range_accepts_values_within_bounds() {
+    let json = r#"{"port": "8080", "ratio": 0.5, "retries": 3}"#;
+    let endpoint: Endpoint = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        endpoint,
+        Endpoint {
+            port: 8080,
+            ratio: 0.5,
+            retries: Some(3),
+        }
+    );
+}
+
+#[test]
+fn test_range_inclusive_edges() {
+    let json = r#"{"port": 65535, "ratio": "1.0", "retries": null}"#;
+    let endpoint: Endpoint = serde_json::from_str(json).unwrap();
+    assert_eq!(endpoint.port, 65535);
+    assert_eq!(endpoint.ratio, 1.0);
+    assert_eq!(endpoint.retries, None);
+}
+
+#[test]
+fn test_range_rejects_out_of_bounds() {
+    let json = r#"{"port": "70000", "ratio": 0.5}"#;
+    let err = serde_json::from_str::<Endpoint>(json).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid value: 70000 for field `port`, expected a value in range 1..=65535 at line 1 column 16"
+    );
+}
+
+#[test]
+fn test_range_rejects_float_out_of_bounds() {
+    let json = r#"{"port": 80, "ratio": 1.5}"#;
+    let err = serde_json::from_str::<Endpoint>(json).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("expected a value in range 0.0..=1.0")
+    );
+}
+
+#[test]
+fn test_min_on_option_field() {
+    let json = r#"{"port": 80, "ratio": 0.0, "retries": "0"}"#;
+    let err = serde_json::from_str::<Endpoint>(json).unwrap_err();
+    assert!(err.to_string().contains("0 for field `retries`"));
+    assert!(err.to_string().contains("1.."));
+}
+
+#[test]
+fn test_range_on_strict_field() {
+    let json = r#"{"level": 10, "offset": "-5"}"#;
+    let data: StrictBounds = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        data,
+        StrictBounds {
+            level: 10,
+            offset: -5
+        }
+    );
+
+    let json = r#"{"level": 11, "offset": 0}"#;
+    assert!(serde_json::from_str::<StrictBounds>(json).is_err());
+}
+
+#[test]
+fn test_range_exclusive_end() {
+    let json = r#"{"level": 0, "offset": 5}"#;
+    let err = serde_json::from_str::<StrictBounds>(json).unwrap_err();
+    assert!(err.to_string().contains("expected a value in range -5..5"));
+}
+
+#[test]
+fn test_range_tuple_lenient() {
+    let sample: Sample = serde_json::from_str(r#"["99.5", "7"]"#).unwrap();
+    assert_eq!(
+        sample,
+        Sample {
+            percent: 99.5,
+            id: 7
+        }
+    );
+
+    assert!(serde_json::from_str::<Sample>(r#"[100.5, 7]"#).is_err());
+}
+
+#[test]
+fn test_range_strict_tuple() {
+    let pixel: Pixel = serde_json::from_str(r#"[255, "white"]"#).unwrap();
+    assert_eq!(
+        pixel,
+        Pixel {
+            r: 255,
+            label: "white".to_string()
+        }
+    );
+
+    let err = serde_json::from_str::<Pixel>(r#"[256, "white"]"#).unwrap_err();
+    assert!(err.to_string().contains("256 for field `r`"));
+}
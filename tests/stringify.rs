@@ -0,0 +1,118 @@
+use serde::Deserialize;
+use serde_tuplex::{Lenient, TupleLenient, lenient_string};
+
+#[derive(Debug, Lenient, PartialEq)]
+struct Account {
+    #[serde_tuplex(stringify)]
+    id: String,
+    #[serde_tuplex(stringify)]
+    reference: Option<String>,
+    #[serde_tuplex(stringify)]
+    code: Box<str>,
+    name: String,
+}
+
+#[derive(Debug, TupleLenient, PartialEq)]
+struct Row {
+    #[serde_tuplex(stringify)]
+    id: String,
+    amount: u32,
+}
+
+#[test]
+fn test_stringify_accepts_numbers_and_strings() {
+    let json = r#"{"id": 12345, "reference": "A-1", "code": 7, "name": "x"}"#;
+    let account: Account = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        account,
+        Account {
+            id: "12345".to_string(),
+            reference: Some("A-1".to_string()),
+            code: "7".into(),
+            name: "x".to_string(),
+        }
+    );
+
+    let json = r#"{"id": "12345", "reference": 99, "code": "abc", "name": "x"}"#;
+    let account: Account = serde_json::from_str(json).unwrap();
+    assert_eq!(account.id, "12345");
+    assert_eq!(account.reference.as_deref(), Some("99"));
+    assert_eq!(&*account.code, "abc");
+}
+
+#[test]
+fn test_stringify_canonical_rendering() {
+    let cases = [
+        ("-42", "-42"),
+        ("18446744073709551615", "18446744073709551615"),
+        ("1.5", "1.5"),
+        // Exact number text is kept verbatim under `arbitrary_precision`.
+        (
+            "3.0",
+            if cfg!(feature = "arbitrary_precision") {
+                "3.0"
+            } else {
+                "3"
+            },
+        ),
+        ("true", "true"),
+        ("false", "false"),
+    ];
+    for (input, expected) in cases {
+        let json = format!(
+            r#"{{"id": {}, "reference": null, "code": "c", "name": "x"}}"#,
+            input
+        );
+        let account: Account = serde_json::from_str(&json).unwrap();
+        assert_eq!(account.id, expected, "input {}", input);
+    }
+}
+
+#[test]
+fn test_stringify_option_missing_and_null() {
+    let json = r#"{"id": 1, "reference": null, "code": "c", "name": "x"}"#;
+    let account: Account = serde_json::from_str(json).unwrap();
+    assert_eq!(account.reference, None);
+
+    let json = r#"{"id": 1, "code": "c", "name": "x"}"#;
+    let account: Account = serde_json::from_str(json).unwrap();
+    assert_eq!(account.reference, None);
+}
+
+#[test]
+fn test_stringify_is_opt_in() {
+    let json = r#"{"id": 1, "reference": null, "code": "c", "name": 5}"#;
+    assert!(serde_json::from_str::<Account>(json).is_err());
+
+    let json = r#"{"id": [1], "reference": null, "code": "c", "name": "x"}"#;
+    assert!(serde_json::from_str::<Account>(json).is_err());
+}
+
+#[test]
+fn test_stringify_tuple_lenient() {
+    let row: Row = serde_json::from_str(r#"[987, "5"]"#).unwrap();
+    assert_eq!(
+        row,
+        Row {
+            id: "987".to_string(),
+            amount: 5
+        }
+    );
+    assert_eq!(serde_json::to_string(&row).unwrap(), r#"["987",5]"#);
+}
+
+#[test]
+fn test_lenient_string_function() {
+    #[derive(Deserialize)]
+    struct Wrapper {
+        #[serde(deserialize_with = "lenient_string")]
+        id: String,
+    }
+
+    let w: Wrapper = serde_json::from_str(r#"{"id": 42}"#).unwrap();
+    assert_eq!(w.id, "42");
+    let w: Wrapper = serde_json::from_str(r#"{"id": "042"}"#).unwrap();
+    assert_eq!(w.id, "042");
+    let w: Wrapper = serde_json::from_str(r#"{"id": 0.25}"#).unwrap();
+    assert_eq!(w.id, "0.25");
+}
@@ -0,0 +1,164 @@
+#![cfg(any(feature = "chrono", feature = "time"))]
+
+use serde_tuplex::{LenientValue, TimestampUnit};
+
+#[cfg(feature = "chrono")]
+mod chrono_fields {
+    use chrono::{DateTime, TimeZone, Utc};
+    use serde_tuplex::{Lenient, TupleLenient};
+
+    #[derive(Debug, Lenient, PartialEq)]
+    struct Event {
+        at: DateTime<Utc>,
+        #[serde_tuplex(timestamp = "ms")]
+        seen: Option<chrono::DateTime<chrono::Utc>>,
+        #[serde_tuplex(timestamp = "auto")]
+        any: DateTime<Utc>,
+    }
+
+    #[derive(Debug, TupleLenient, PartialEq)]
+    struct Row {
+        #[serde_tuplex(timestamp = "ms", serialize = "epoch")]
+        millis: DateTime<Utc>,
+        #[serde_tuplex(serialize = "epoch")]
+        secs: Option<DateTime<Utc>>,
+    }
+
+    fn utc(secs: i64, nanos: u32) -> DateTime<Utc> {
+        Utc.timestamp_opt(secs, nanos).unwrap()
+    }
+
+    #[test]
+    fn test_chrono_units() {
+        let json = r#"{"at": 1700000000, "seen": "1700000000123", "any": "1700000000"}"#;
+        let event: Event = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            event,
+            Event {
+                at: utc(1_700_000_000, 0),
+                seen: Some(utc(1_700_000_000, 123_000_000)),
+                any: utc(1_700_000_000, 0),
+            }
+        );
+
+        let json = r#"{"at": "2023-11-14T22:13:20Z", "seen": null, "any": 1700000000123456}"#;
+        let event: Event = serde_json::from_str(json).unwrap();
+        assert_eq!(event.at, utc(1_700_000_000, 0));
+        assert_eq!(event.seen, None);
+        assert_eq!(event.any, utc(1_700_000_000, 123_456_000));
+    }
+
+    #[test]
+    fn test_chrono_forms_and_errors() {
+        let cases = [
+            (
+                r#"{"at": 1700000000.5, "any": 0}"#,
+                utc(1_700_000_000, 500_000_000),
+            ),
+            (r#"{"at": " 1700000000 ", "any": 0}"#, utc(1_700_000_000, 0)),
+            (r#"{"at": -1, "any": 0}"#, utc(-1, 0)),
+            (
+                r#"{"at": "2023-11-15T00:13:20.25+02:00", "any": 0}"#,
+                utc(1_700_000_000, 250_000_000),
+            ),
+        ];
+        for (json, expected) in cases {
+            let event: Event = serde_json::from_str(json).unwrap();
+            assert_eq!(event.at, expected, "input {}", json);
+        }
+
+        let cases = [
+            (r#"{"at": "yesterday", "any": 0}"#, "invalid timestamp"),
+            (r#"{"at": 1e30, "any": 0}"#, "out of range"),
+            (r#"{"at": true, "any": 0}"#, ""),
+        ];
+        for (json, message) in cases {
+            let err = serde_json::from_str::<Event>(json).unwrap_err();
+            assert!(err.to_string().contains(message), "{}: {}", json, err);
+        }
+    }
+
+    #[test]
+    fn test_chrono_serialize_epoch() {
+        let row = Row {
+            millis: utc(1_700_000_000, 123_000_000),
+            secs: Some(utc(1_700_000_000, 500_000_000)),
+        };
+        let json = serde_json::to_string(&row).unwrap();
+        assert_eq!(json, "[1700000000123,1700000000.5]");
+        assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), row);
+
+        let row = Row {
+            millis: utc(0, 0),
+            secs: None,
+        };
+        assert_eq!(serde_json::to_string(&row).unwrap(), "[0,null]");
+    }
+}
+
+#[cfg(feature = "time")]
+mod time_fields {
+    use serde_tuplex::{Lenient, TupleLenient};
+    use time::OffsetDateTime;
+
+    #[derive(Debug, Lenient, PartialEq)]
+    struct Event {
+        #[serde_tuplex(timestamp = "auto")]
+        at: OffsetDateTime,
+        seen: Option<time::OffsetDateTime>,
+    }
+
+    #[derive(Debug, TupleLenient, PartialEq)]
+    struct Row {
+        #[serde_tuplex(timestamp = "us", serialize = "epoch")]
+        at: OffsetDateTime,
+    }
+
+    fn at(nanos: i128) -> OffsetDateTime {
+        OffsetDateTime::from_unix_timestamp_nanos(nanos).unwrap()
+    }
+
+    #[test]
+    fn test_time_fields() {
+        let json = r#"{"at": 1700000000123, "seen": "2023-11-14T22:13:20Z"}"#;
+        let event: Event = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            event,
+            Event {
+                at: at(1_700_000_000_123_000_000),
+                seen: Some(at(1_700_000_000_000_000_000)),
+            }
+        );
+
+        let json = r#"{"at": "1700000000123456789", "seen": "1700000000"}"#;
+        let event: Event = serde_json::from_str(json).unwrap();
+        assert_eq!(event.at, at(1_700_000_000_123_456_789));
+        assert_eq!(event.seen, Some(at(1_700_000_000_000_000_000)));
+
+        let row = Row {
+            at: at(1_700_000_000_123_456_000),
+        };
+        let json = serde_json::to_string(&row).unwrap();
+        assert_eq!(json, "[1700000000123456]");
+        assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), row);
+    }
+}
+
+#[test]
+fn test_parse_timestamp() {
+    #[cfg(feature = "chrono")]
+    {
+        let value = LenientValue::U64(1_700_000_000_000);
+        let parsed = value.parse_timestamp::<chrono::DateTime<chrono::Utc>>(TimestampUnit::Auto);
+        assert_eq!(parsed.unwrap().timestamp(), 1_700_000_000);
+        let parsed: Result<chrono::DateTime<chrono::Utc>, _> =
+            serde_tuplex::lenient(serde_json::json!("2023-11-14T22:13:20Z"));
+        assert_eq!(parsed.unwrap().timestamp(), 1_700_000_000);
+    }
+    #[cfg(feature = "time")]
+    {
+        let value = LenientValue::F64(1.5);
+        let parsed = value.parse_timestamp::<time::OffsetDateTime>(TimestampUnit::Secs);
+        assert_eq!(parsed.unwrap().unix_timestamp_nanos(), 1_500_000_000);
+    }
+}
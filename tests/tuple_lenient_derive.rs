@@ -338,6 +338,11 @@ fn test_tuple_lenient_scientific_notation() {
     let measurement: Measurement = serde_json::from_str(json).unwrap();
     assert_eq!(measurement.timestamp, 1000);
     assert_eq!(measurement.value, 1.23e10);
+
+    let json = r#"["1.7e9",1e3]"#;
+    let measurement: Measurement = serde_json::from_str(json).unwrap();
+    assert_eq!(measurement.timestamp, 1_700_000_000);
+    assert_eq!(measurement.value, 1000.0);
 }
 
 #[test]
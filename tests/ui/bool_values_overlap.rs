@@ -0,0 +1,21 @@
+use serde_tuplex::Lenient;
+
+#[derive(Lenient)]
+struct Enabled {
+    #[serde_tuplex(true_values = ["yes", "no"])]
+    value: bool,
+}
+
+#[derive(Lenient)]
+struct Visible {
+    #[serde_tuplex(false_values = ["nope", "ON"])]
+    value: bool,
+}
+
+#[derive(Lenient)]
+struct Active {
+    #[serde_tuplex(true_values = ["ja"], false_values = ["nein", "JA"])]
+    value: bool,
+}
+
+fn main() {}
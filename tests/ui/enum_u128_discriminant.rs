@@ -0,0 +1,10 @@
+use serde_tuplex::LenientEnum;
+
+#[derive(LenientEnum)]
+#[repr(u128)]
+enum Flag {
+    Off = 0,
+    On = u128::MAX,
+}
+
+fn main() {}
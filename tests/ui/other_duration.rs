@@ -0,0 +1,29 @@
+use serde_tuplex::{Lenient, TupleLenient};
+
+mod chrono {
+    pub struct Duration;
+}
+
+mod time {
+    pub struct Duration;
+}
+
+#[derive(Lenient)]
+struct Timeout {
+    #[serde_tuplex(duration = "s")]
+    value: chrono::Duration,
+}
+
+#[derive(Lenient)]
+struct Backoff {
+    #[serde_tuplex(duration = "ms")]
+    value: Option<time::Duration>,
+}
+
+#[derive(TupleLenient)]
+struct Interval {
+    #[serde_tuplex(serialize = "iso8601")]
+    value: chrono::Duration,
+}
+
+fn main() {}
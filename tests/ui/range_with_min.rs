@@ -0,0 +1,15 @@
+use serde_tuplex::Lenient;
+
+#[derive(Lenient)]
+struct Endpoint {
+    #[serde_tuplex(range = 1..=65535, min = 10)]
+    port: u32,
+}
+
+#[derive(Lenient)]
+struct Ratio {
+    #[serde_tuplex(max = 1.0, range = 0.0..)]
+    value: f64,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use serde_tuplex::Lenient;
+
+#[derive(Lenient)]
+struct Config {
+    #[serde_tuplex(lenietn)]
+    timeout: u64,
+}
+
+fn main() {}